@@ -0,0 +1,265 @@
+use std::{
+    fs::File,
+    io::{self, Read, Seek},
+    path::{Path, PathBuf},
+    sync::Mutex,
+};
+
+use zip::{result::ZipError, ZipArchive};
+
+use crate::api::{
+    EnumerateResources, LoadResource, ResourceCategory, ResourceIdentifier, ResourceKind,
+};
+
+/// A [`ResourceProvider`] that provides resources from a zipped pack.
+///
+/// The archive should contain the `assets/` and (optionally) `data/`
+/// directories at its root, as is the case for resource packs and data packs
+/// distributed as `.zip` files.
+///
+/// **Requires the `zip` feature.**
+///
+/// [`ResourceProvider`]: crate::api::ResourceProvider
+///
+/// # Example
+///
+/// ```no_run
+/// use minecraft_assets::api::*;
+///
+/// let provider = ZipResourceProvider::open("~/Downloads/my_pack.zip").unwrap();
+/// let assets = AssetPack::new(provider);
+///
+/// let states = assets.load_blockstates("stone").unwrap();
+/// ```
+pub struct ZipResourceProvider<R = File> {
+    archive: Mutex<ZipArchive<R>>,
+}
+
+impl ZipResourceProvider<File> {
+    /// Opens the zip file at the given path.
+    ///
+    /// Returns an error of kind [`InvalidData`][io::ErrorKind::InvalidData]
+    /// if the file is not a valid zip archive.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, io::Error> {
+        Self::new(File::open(path)?)
+    }
+}
+
+impl<R: Read + Seek> ZipResourceProvider<R> {
+    /// Returns a new provider that reads the zip archive from `reader`.
+    ///
+    /// Returns an error of kind [`InvalidData`][io::ErrorKind::InvalidData]
+    /// if the data is not a valid zip archive.
+    pub fn new(reader: R) -> Result<Self, io::Error> {
+        let archive = ZipArchive::new(reader).map_err(zip_to_io_error)?;
+
+        Ok(Self {
+            archive: Mutex::new(archive),
+        })
+    }
+
+    /// Returns the name of the entry in the archive that holds the given
+    /// resource.
+    fn entry_name(id: &ResourceIdentifier) -> String {
+        let kind = id.kind();
+
+        format!(
+            "{}/{}/{}/{}",
+            kind.category().directory(),
+            id.namespace(),
+            kind.directory(),
+            kind.file_name(id.path())
+        )
+    }
+}
+
+impl<R: Read + Seek> EnumerateResources for ZipResourceProvider<R> {
+    fn enumerate_resources(
+        &self,
+        namespace: &str,
+        kind: ResourceKind,
+    ) -> Result<Vec<ResourceIdentifier<'static>>, io::Error> {
+        let prefix = format!(
+            "{}/{}/{}/",
+            kind.category().directory(),
+            namespace,
+            kind.directory()
+        );
+        let archive = self.archive.lock().unwrap();
+
+        let mut found_directory = false;
+        let mut ids = Vec::new();
+
+        for name in archive.file_names() {
+            let path = match name.strip_prefix(&prefix) {
+                Some(path) => path,
+                None => continue,
+            };
+            found_directory = true;
+
+            // Skip over files starting with '_', like `ResourceIter` does.
+            let file_name = path.rsplit('/').next().unwrap();
+            if file_name.starts_with('_') {
+                continue;
+            }
+
+            if let Some(path) = kind.strip_extension(path) {
+                ids.push(ResourceIdentifier::new_owned(kind, path.to_string()));
+            }
+        }
+
+        if !found_directory {
+            return Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("no `{}` directory in archive", prefix),
+            ));
+        }
+
+        Ok(ids)
+    }
+
+    fn enumerate_namespaces(&self, category: ResourceCategory) -> Result<Vec<String>, io::Error> {
+        let prefix = format!("{}/", category.directory());
+
+        let archive = self.archive.lock().unwrap();
+
+        let mut namespaces: Vec<String> = archive
+            .file_names()
+            .filter_map(|name| name.strip_prefix(&prefix))
+            .filter_map(|path| path.split_once('/'))
+            .map(|(namespace, _)| namespace.to_string())
+            .filter(|namespace| !namespace.is_empty() && !namespace.starts_with(['.', '_']))
+            .collect();
+
+        namespaces.sort();
+        namespaces.dedup();
+        Ok(namespaces)
+    }
+}
+
+impl<R: Read + Seek> LoadResource for ZipResourceProvider<R> {
+    fn load_resource(&self, id: &ResourceIdentifier) -> Result<Vec<u8>, io::Error> {
+        let mut archive = self.archive.lock().unwrap();
+
+        let mut file = archive
+            .by_name(&Self::entry_name(id))
+            .map_err(zip_to_io_error)?;
+
+        let mut bytes = Vec::with_capacity(file.size() as usize);
+        file.read_to_end(&mut bytes)?;
+
+        Ok(bytes)
+    }
+
+    fn contains(&self, id: &ResourceIdentifier) -> bool {
+        let name = Self::entry_name(id);
+        let mut archive = self.archive.lock().unwrap();
+        let found = archive.by_name(&name).is_ok();
+        found
+    }
+}
+
+/// A [`ResourceProvider`] that provides the vanilla resources stored in a
+/// Minecraft client jar (e.g., `.minecraft/versions/1.19.4/1.19.4.jar`).
+///
+/// A client jar is a zip archive that stores the `assets/` and `data/`
+/// directories at its root, alongside the game's code, so this provider
+/// reads it the same way as a [`ZipResourceProvider`]. This saves extracting
+/// the jar to analyze the vanilla resources.
+///
+/// **Requires the `zip` feature.**
+///
+/// [`ResourceProvider`]: crate::api::ResourceProvider
+///
+/// # Example
+///
+/// ```no_run
+/// use minecraft_assets::api::*;
+///
+/// let provider = JarResourceProvider::open_version("~/.minecraft", "1.19.4").unwrap();
+/// let assets = AssetPack::new(provider);
+///
+/// let states = assets.load_blockstates("stone").unwrap();
+/// ```
+pub struct JarResourceProvider {
+    archive: ZipResourceProvider<File>,
+}
+
+impl JarResourceProvider {
+    /// Opens the client jar at the given path.
+    ///
+    /// Returns an error of kind [`InvalidData`][io::ErrorKind::InvalidData]
+    /// if the file is not a valid jar.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, io::Error> {
+        Ok(Self {
+            archive: ZipResourceProvider::open(path)?,
+        })
+    }
+
+    /// Opens the client jar of the given version in the `.minecraft`
+    /// directory at `minecraft_dir`.
+    ///
+    /// See [`version_jar_path()`][Self::version_jar_path]. Returns an error of
+    /// kind [`NotFound`][io::ErrorKind::NotFound] if the version is not
+    /// installed.
+    pub fn open_version(minecraft_dir: impl AsRef<Path>, version: &str) -> Result<Self, io::Error> {
+        Self::open(Self::version_jar_path(minecraft_dir, version))
+    }
+
+    /// Returns the path of the client jar of the given version in the
+    /// standard layout of the `.minecraft` directory at `minecraft_dir`, i.e.,
+    /// `versions/<version>/<version>.jar`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::api::*;
+    /// use std::path::Path;
+    ///
+    /// assert_eq!(
+    ///     JarResourceProvider::version_jar_path("/home/me/.minecraft", "1.19.4"),
+    ///     Path::new("/home/me/.minecraft/versions/1.19.4/1.19.4.jar")
+    /// );
+    /// ```
+    pub fn version_jar_path(minecraft_dir: impl AsRef<Path>, version: &str) -> PathBuf {
+        minecraft_dir
+            .as_ref()
+            .join("versions")
+            .join(version)
+            .join(format!("{}.jar", version))
+    }
+}
+
+impl EnumerateResources for JarResourceProvider {
+    fn enumerate_resources(
+        &self,
+        namespace: &str,
+        kind: ResourceKind,
+    ) -> Result<Vec<ResourceIdentifier<'static>>, io::Error> {
+        self.archive.enumerate_resources(namespace, kind)
+    }
+
+    fn enumerate_namespaces(&self, category: ResourceCategory) -> Result<Vec<String>, io::Error> {
+        self.archive.enumerate_namespaces(category)
+    }
+}
+
+impl LoadResource for JarResourceProvider {
+    fn load_resource(&self, id: &ResourceIdentifier) -> Result<Vec<u8>, io::Error> {
+        self.archive.load_resource(id)
+    }
+
+    fn contains(&self, id: &ResourceIdentifier) -> bool {
+        self.archive.contains(id)
+    }
+}
+
+fn zip_to_io_error(error: ZipError) -> io::Error {
+    match error {
+        ZipError::Io(error) => error,
+        ZipError::FileNotFound => io::Error::new(io::ErrorKind::NotFound, error),
+        ZipError::InvalidArchive(_) | ZipError::UnsupportedArchive(_) => {
+            io::Error::new(io::ErrorKind::InvalidData, error)
+        }
+    }
+}
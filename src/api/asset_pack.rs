@@ -1,19 +1,66 @@
-use std::{ops::Deref, path::Path};
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet},
+    fs, io,
+    ops::Deref,
+    path::{Path, PathBuf},
+    sync::Mutex,
+};
 
-use serde::de::DeserializeOwned;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
 
 use crate::{
     api::{
-        FileSystemResourceProvider, ModelIdentifier, ResourceIdentifier, ResourceProvider, Result,
+        cache::LruCache, provider::ResourceIter, Error, FileSystemResourceProvider,
+        ModelIdentifier, ModelResolver, ResourceCategory, ResourceIdentifier, ResourceKind,
+        ResourcePath, ResourceProvider, Result,
+    },
+    schemas::{
+        blockstates::{multipart::WhenClause, BlockStateProperties, ModelProperties, Variant},
+        models::Texture,
+        optifine::Properties,
+        AtlasDefinition, BlockStates, FontDefinition, Model, ParticleDefinition, Recipe, Tag,
     },
-    schemas::{BlockStates, Model},
 };
 
 /// Top-level API for accessing Minecraft assets.
+///
+/// An [`AssetPack`] is [`Send`] and [`Sync`], and all of its `load_*` methods
+/// take `&self`, so a single pack can be wrapped in an
+/// [`Arc`][std::sync::Arc] and used from many threads at once. Its caches are
+/// guarded by mutexes, so every thread shares them.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::{sync::Arc, thread};
+/// use minecraft_assets::api::AssetPack;
+///
+/// let assets = Arc::new(AssetPack::at_path("~/.minecraft/").with_cache());
+///
+/// let handles: Vec<_> = ["oak_planks", "stone"]
+///     .into_iter()
+///     .map(|block| {
+///         let assets = Arc::clone(&assets);
+///         thread::spawn(move || assets.load_blockstates(block).unwrap())
+///     })
+///     .collect();
+///
+/// for handle in handles {
+///     let states = handle.join().unwrap();
+/// }
+/// ```
 pub struct AssetPack {
     provider: Box<dyn ResourceProvider>,
+    root: Option<PathBuf>,
+    resolve_cache: Option<LruCache<(String, String), Vec<PlacedModel>>>,
+    enumeration_cache: Option<Mutex<EnumerationCache>>,
+    model_cache: Option<Mutex<HashMap<ResourceIdentifier<'static>, Model>>>,
+    strict: bool,
+    lenient: bool,
 }
 
+type EnumerationCache = HashMap<(String, ResourceKind), Vec<ResourceIdentifier<'static>>>;
+
 impl AssetPack {
     /// Returns a new [`AssetPack`] that can read data from the given directory.
     ///
@@ -37,12 +84,37 @@ impl AssetPack {
     /// assert_eq!(model_properties.model, "block/oak_planks");
     /// ```
     pub fn at_path(root_dir: impl AsRef<Path>) -> Self {
-        let provider = FileSystemResourceProvider::new(root_dir);
+        let provider = FileSystemResourceProvider::new(&root_dir);
         Self {
-            provider: Box::new(provider),
+            root: Some(PathBuf::from(root_dir.as_ref())),
+            ..Self::new(provider)
         }
     }
 
+    /// Returns a new [`AssetPack`] that reads data from the zip file at the
+    /// given path.
+    ///
+    /// The archive should contain the `assets/` and/or `data/` directories at
+    /// its root. Returns an error if the file cannot be opened or is not a
+    /// valid zip archive.
+    ///
+    /// **Requires the `zip` feature.**
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use minecraft_assets::api::AssetPack;
+    ///
+    /// let assets = AssetPack::from_zip("~/Downloads/my_pack.zip").unwrap();
+    ///
+    /// let states = assets.load_blockstates("oak_planks").unwrap();
+    /// ```
+    #[cfg(feature = "zip")]
+    pub fn from_zip(path: impl AsRef<Path>) -> Result<Self> {
+        let provider = crate::api::ZipResourceProvider::open(path)?;
+        Ok(Self::new(provider))
+    }
+
     /// Returns a new [`AssetPack`] that uses the given [`ResourceProvider`].
     pub fn new<P>(provider: P) -> Self
     where
@@ -50,148 +122,2585 @@ impl AssetPack {
     {
         Self {
             provider: Box::new(provider),
+            root: None,
+            resolve_cache: None,
+            enumeration_cache: None,
+            model_cache: None,
+            strict: false,
+            lenient: false,
         }
     }
 
-    /// Loads the [`BlockStates`] of the block with the provided id.
+    /// Enables (`true`) or disables (`false` - default) strict validation of
+    /// loaded resources.
+    ///
+    /// In strict mode, loading [`BlockStates`] that contain a model rotation
+    /// other than `0`, `90`, `180`, or `270` fails with
+    /// [`Error::InvalidRotation`]. Otherwise, such rotations are accepted
+    /// as-is.
     ///
     /// # Example
     ///
     /// ```no_run
     /// # use minecraft_assets::api::*;
-    /// # let assets = AssetPack::at_path("foo");
-    /// let states = assets.load_blockstates("stone");
-    /// let states = assets.load_blockstates("minecraft:dirt");
+    /// let assets = AssetPack::at_path("~/.minecraft/").strict(true);
+    ///
+    /// match assets.load_blockstates("stone") {
+    ///     Err(Error::InvalidRotation { value }) => println!("bad rotation: {}", value),
+    ///     _ => {}
+    /// }
     /// ```
-    pub fn load_blockstates(&self, block_id: &str) -> Result<BlockStates> {
-        self.load_resource(&ResourceIdentifier::blockstates(block_id))
+    pub fn strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
     }
 
-    /// Loads the block [`Model`] identified by the given name or path.
+    /// Enables (`true`) or disables (`false` - default) lenient parsing of
+    /// loaded resources.
+    ///
+    /// Community packs sometimes contain files that the game accepts but that
+    /// are not valid JSON. In lenient mode, every `load_*` method parses files
+    /// as [JSON5] instead, which tolerates:
+    ///
+    /// * `// line` and `/* block */` comments.
+    /// * Trailing commas in objects and arrays.
+    /// * Unquoted object keys (e.g., `{ model: "block/stone" }`).
+    /// * Single-quoted strings.
+    /// * Hexadecimal numbers, and numbers with a leading `+` or a leading or
+    ///   trailing decimal point.
+    ///
+    /// Lenient parsing is slower than the default strict parsing, and its
+    /// errors do not report line and column numbers.
+    ///
+    /// **Requires the `json5` feature.**
+    ///
+    /// [JSON5]: <https://json5.org/>
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use minecraft_assets::api::*;
+    /// let assets = AssetPack::at_path("~/Downloads/my_pack/").lenient(true);
+    ///
+    /// let states = assets.load_blockstates("stone").unwrap();
+    /// ```
+    #[cfg(feature = "json5")]
+    pub fn lenient(mut self, lenient: bool) -> Self {
+        self.lenient = lenient;
+        self
+    }
+
+    /// Enables caching of the models returned by
+    /// [`resolve_block()`][Self::resolve_block].
+    ///
+    /// At most `capacity` block states are kept in the cache. When the cache
+    /// is full, the least-recently-used entry is evicted. The cache is safe to
+    /// access from multiple threads.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use minecraft_assets::api::*;
+    /// let assets = AssetPack::at_path("~/.minecraft/").with_resolve_cache(4096);
+    /// ```
+    pub fn with_resolve_cache(mut self, capacity: usize) -> Self {
+        self.resolve_cache = Some(LruCache::new(capacity));
+        self
+    }
+
+    /// Removes all entries from the cache enabled by
+    /// [`with_resolve_cache()`][Self::with_resolve_cache].
+    ///
+    /// This should be called if the underlying resources change.
+    pub fn invalidate_resolve_cache(&self) {
+        if let Some(ref cache) = self.resolve_cache {
+            cache.clear();
+        }
+    }
+
+    /// Enables caching of the results of
+    /// [`enumerate_resources()`][Self::enumerate_resources].
+    ///
+    /// The list of resources of each kind in each namespace is only read from
+    /// the underlying [`ResourceProvider`] once. The cache is safe to access
+    /// from multiple threads.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use minecraft_assets::api::*;
+    /// let assets = AssetPack::at_path("~/.minecraft/").with_enumeration_cache();
+    ///
+    /// // Walks the `blockstates/` directory.
+    /// let blocks = assets.enumerate_resources("minecraft", ResourceKind::BlockStates);
+    ///
+    /// // Returns the cached list.
+    /// let blocks = assets.enumerate_resources("minecraft", ResourceKind::BlockStates);
+    /// ```
+    pub fn with_enumeration_cache(mut self) -> Self {
+        self.enumeration_cache = Some(Mutex::new(HashMap::new()));
+        self
+    }
+
+    /// Removes all entries from the cache enabled by
+    /// [`with_enumeration_cache()`][Self::with_enumeration_cache].
+    ///
+    /// This should be called if resources are added or removed.
+    pub fn invalidate_enumeration(&self) {
+        if let Some(ref cache) = self.enumeration_cache {
+            cache.lock().unwrap().clear();
+        }
+    }
+
+    /// Enables caching of parsed block and item [`Model`]s.
+    ///
+    /// Each model is only read and parsed once, the first time it is loaded,
+    /// which avoids re-reading common parents like `block/block` for every
+    /// model that inherits from them. Later loads return a clone of the cached
+    /// model.
+    ///
+    /// The cache is kept behind a [`Mutex`], so it is safe to access from
+    /// multiple threads. The lock is not held while a model is being read, so
+    /// two threads that load the same uncached model at the same time may
+    /// both read it.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use minecraft_assets::api::*;
+    /// let assets = AssetPack::at_path("~/.minecraft/").with_cache();
+    ///
+    /// // Reads `block/cube_all`, `block/cube`, and `block/block`.
+    /// let models = assets.load_block_model_recursive("stone");
+    ///
+    /// // Only reads `block/dirt`.
+    /// let models = assets.load_block_model_recursive("dirt");
+    /// ```
+    pub fn with_cache(mut self) -> Self {
+        self.model_cache = Some(Mutex::new(HashMap::new()));
+        self
+    }
+
+    /// Removes all models from the cache enabled by
+    /// [`with_cache()`][Self::with_cache].
+    ///
+    /// This should be called if the underlying resources change.
+    pub fn clear_cache(&self) {
+        if let Some(ref cache) = self.model_cache {
+            cache.lock().unwrap().clear();
+        }
+    }
+
+    /// Loads every model of the given kinds in the given namespace into the
+    /// cache enabled by [`with_cache()`][Self::with_cache], so that later
+    /// loads of those models do not read from the provider.
+    ///
+    /// Each of `kinds` must be [`BlockModel`] or [`ItemModel`], since only
+    /// models are cached. Otherwise, an error of kind
+    /// [`InvalidInput`][io::ErrorKind::InvalidInput] is returned. Kinds with
+    /// no directory in the namespace are skipped, as are models that are
+    /// already cached. If any model fails to load, the error is returned and
+    /// nothing is added to the cache.
+    ///
+    /// With the `rayon` feature enabled, the models are parsed in parallel.
+    /// The files are always read one at a time.
+    ///
+    /// If the cache is not enabled, this does nothing.
+    ///
+    /// [`BlockModel`]: ResourceKind::BlockModel
+    /// [`ItemModel`]: ResourceKind::ItemModel
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use minecraft_assets::api::*;
+    /// let assets = AssetPack::at_path("~/.minecraft/").with_cache();
+    /// assets
+    ///     .preload("minecraft", &[ResourceKind::BlockModel, ResourceKind::ItemModel])
+    ///     .unwrap();
+    ///
+    /// // Does not read from the file system.
+    /// let models = assets.load_block_model_recursive("stone").unwrap();
+    /// ```
+    pub fn preload(&self, namespace: &str, kinds: &[ResourceKind]) -> Result<()> {
+        for &kind in kinds {
+            Self::check_model_kind(kind)?;
+        }
+
+        let cache = match self.model_cache {
+            Some(ref cache) => cache,
+            None => return Ok(()),
+        };
+
+        let mut files = Vec::new();
+
+        for &kind in kinds {
+            for id in self.enumerate_namespaced(namespace, kind)? {
+                if cache.lock().unwrap().contains_key(&id) {
+                    continue;
+                }
+
+                let bytes = self.load_bytes(&id)?;
+                files.push((id, bytes));
+            }
+        }
+
+        #[cfg(feature = "rayon")]
+        let models = {
+            use rayon::prelude::*;
+
+            let root = self.root.as_deref();
+            let lenient = self.lenient;
+            files
+                .into_par_iter()
+                .map(|(id, bytes)| {
+                    let model = Self::parse_bytes(root, lenient, &id, &bytes)?;
+                    Ok((id, model))
+                })
+                .collect::<Result<Vec<_>>>()?
+        };
+
+        #[cfg(not(feature = "rayon"))]
+        let models = files
+            .into_iter()
+            .map(|(id, bytes)| {
+                let model = Self::parse_bytes(self.root.as_deref(), self.lenient, &id, &bytes)?;
+                Ok((id, model))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        cache.lock().unwrap().extend(models);
+
+        Ok(())
+    }
+
+    /// Returns the identifiers of all resources of the given kind in the
+    /// given namespace.
+    ///
+    /// If [`with_enumeration_cache()`][Self::with_enumeration_cache] was
+    /// called, the result is cached. Otherwise, this is the same as calling
+    /// [`EnumerateResources::enumerate_resources()`] on the underlying
+    /// provider.
+    ///
+    /// [`EnumerateResources::enumerate_resources()`]: crate::api::EnumerateResources::enumerate_resources
+    pub fn enumerate_resources(
+        &self,
+        namespace: &str,
+        kind: ResourceKind,
+    ) -> Result<Vec<ResourceIdentifier<'static>>, io::Error> {
+        let cache = match self.enumeration_cache {
+            Some(ref cache) => cache,
+            None => return self.provider.enumerate_resources(namespace, kind),
+        };
+
+        let key = (String::from(namespace), kind);
+        if let Some(ids) = cache.lock().unwrap().get(&key) {
+            return Ok(ids.clone());
+        }
+
+        // Don't hold the lock while reading from the provider.
+        let ids = self.provider.enumerate_resources(namespace, kind)?;
+        cache.lock().unwrap().insert(key, ids.clone());
+
+        Ok(ids)
+    }
+
+    /// Enumerates the resources of the given kind in the given namespace, and
+    /// returns their [canonical] ids in sorted order.
+    ///
+    /// A namespace without a directory for the kind has no resources of that
+    /// kind, rather than being an error.
+    ///
+    /// [canonical]: ResourceIdentifier::to_canonical
+    fn enumerate_namespaced(
+        &self,
+        namespace: &str,
+        kind: ResourceKind,
+    ) -> Result<Vec<ResourceIdentifier<'static>>> {
+        let ids = match self.enumerate_resources(namespace, kind) {
+            Ok(ids) => ids,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(e.into()),
+        };
+
+        let mut ids: Vec<_> = ids
+            .into_iter()
+            .map(|id| {
+                ResourceIdentifier::new_owned(kind, format!("{}:{}", namespace, id.as_str()))
+                    .to_canonical()
+            })
+            .collect();
+        ids.sort_by(|a, b| a.as_str().cmp(b.as_str()));
+
+        Ok(ids)
+    }
+
+    /// Returns the namespaces in the pack, i.e., the names of the directories
+    /// directly inside of `assets/` and `data/`, in sorted order.
+    ///
+    /// Hidden directories and directories whose names start with `_` are
+    /// skipped. A namespace that is in both `assets/` and `data/` is listed
+    /// once. Use
+    /// [`EnumerateResources::enumerate_namespaces()`][crate::api::EnumerateResources::enumerate_namespaces]
+    /// to list the namespaces of only one category. Not every provider
+    /// supports listing namespaces.
     ///
     /// # Example
     ///
     /// ```no_run
     /// # use minecraft_assets::api::*;
     /// # let assets = AssetPack::at_path("foo");
-    /// let model = assets.load_block_model("stone");
-    /// let model = assets.load_block_model("block/dirt");
+    /// for namespace in assets.namespaces().unwrap() {
+    ///     let blocks = assets.enumerate_resources(&namespace, ResourceKind::BlockStates);
+    /// }
     /// ```
-    pub fn load_block_model(&self, model: &str) -> Result<Model> {
-        self.load_resource(&ResourceIdentifier::block_model(model))
+    pub fn namespaces(&self) -> Result<Vec<String>> {
+        let mut namespaces = BTreeSet::new();
+
+        for category in [ResourceCategory::Assets, ResourceCategory::Data] {
+            match self.provider.enumerate_namespaces(category) {
+                Ok(found) => namespaces.extend(found),
+                Err(e) if e.kind() == io::ErrorKind::NotFound => continue,
+                Err(e) => return Err(e.into()),
+            }
+        }
+
+        Ok(namespaces.into_iter().collect())
     }
 
-    /// Loads the block [`Model`] identified by the given name or path, as well
-    /// as all of its parents and ancestors.
+    /// Finds the resources of the given kind that are defined in more than one
+    /// namespace.
     ///
-    /// The models are returned as a list, with the first element being the
-    /// model that was originally requested, the next element being its parent,
-    /// and so on with the last element being the topmost parent.
+    /// Returns a map from the path of each such resource (e.g., `"stone"` or
+    /// `"block/stone"`) to the sorted list of namespaces that define it.
+    /// Namespaces that contain no resources of the given kind are ignored.
+    ///
+    /// The namespaces are listed with
+    /// [`EnumerateResources::enumerate_namespaces()`][crate::api::EnumerateResources::enumerate_namespaces],
+    /// which not every provider supports.
     ///
     /// # Example
     ///
     /// ```no_run
     /// # use minecraft_assets::api::*;
     /// # let assets = AssetPack::at_path("foo");
-    /// let models = assets.load_block_model_recursive("block/cube_all").unwrap();
+    /// let duplicates = assets
+    ///     .find_cross_namespace_duplicates(ResourceKind::BlockStates)
+    ///     .unwrap();
     ///
-    /// let expected = vec![
-    ///     assets.load_block_model("block/cube_all").unwrap(),
-    ///     assets.load_block_model("block/cube").unwrap(),
-    ///     assets.load_block_model("block/block").unwrap(),
-    /// ];
-    /// assert_eq!(models, expected);
+    /// for (path, namespaces) in duplicates {
+    ///     println!("{} is defined in {}", path, namespaces.join(", "));
+    /// }
     /// ```
-    pub fn load_block_model_recursive(&self, model: &str) -> Result<Vec<Model>> {
-        self.load_model_recursive(&ResourceIdentifier::block_model(model))
+    pub fn find_cross_namespace_duplicates(
+        &self,
+        kind: ResourceKind,
+    ) -> Result<HashMap<String, Vec<String>>> {
+        let mut namespaces_by_path: HashMap<String, Vec<String>> = HashMap::new();
+
+        for namespace in self.provider.enumerate_namespaces(kind.category())? {
+            for id in self.enumerate_namespaced(&namespace, kind)? {
+                namespaces_by_path
+                    .entry(id.as_str()[namespace.len() + 1..].to_string())
+                    .or_default()
+                    .push(namespace.clone());
+            }
+        }
+
+        namespaces_by_path.retain(|_, namespaces| namespaces.len() > 1);
+        Ok(namespaces_by_path)
     }
 
-    /// Loads the item [`Model`] identified by the given name or path.
+    /// Returns the [canonical] ids of the resources of the given kind in the
+    /// given namespace whose paths match the given glob pattern.
+    ///
+    /// The pattern is matched against the whole path of each resource, without
+    /// the namespace but with the `block/` or `item/` prefix of models (e.g.,
+    /// `block/oak_planks`). In the pattern:
+    ///
+    /// * `*` matches any sequence of characters other than `/`.
+    /// * `**` matches any sequence of characters, including `/`. When followed
+    ///   by `/`, it also matches no directories at all, so `item/**/layer*`
+    ///   matches both `item/layer0` and `item/a/b/layer0`.
+    ///
+    /// Every other character matches itself. The ids are returned in sorted
+    /// order.
+    ///
+    /// [canonical]: ResourceIdentifier::to_canonical
     ///
     /// # Example
     ///
     /// ```no_run
     /// # use minecraft_assets::api::*;
     /// # let assets = AssetPack::at_path("foo");
-    /// let model = assets.load_item_model("compass");
-    /// let model = assets.load_item_model("item/diamond_hoe");
+    /// let stairs = assets
+    ///     .glob_resources("minecraft", ResourceKind::BlockModel, "block/*_stairs")
+    ///     .unwrap();
+    ///
+    /// assert!(stairs.contains(&ResourceIdentifier::block_model("oak_stairs")));
     /// ```
-    pub fn load_item_model(&self, model: &str) -> Result<Model> {
-        self.load_resource(&ResourceIdentifier::item_model(model))
+    pub fn glob_resources(
+        &self,
+        namespace: &str,
+        kind: ResourceKind,
+        pattern: &str,
+    ) -> Result<Vec<ResourceIdentifier<'static>>> {
+        let mut ids = self.enumerate_namespaced(namespace, kind)?;
+        ids.retain(|id| {
+            let path = &id.as_str()[namespace.len() + 1..];
+            glob_match(pattern.as_bytes(), path.as_bytes())
+        });
+
+        Ok(ids)
     }
 
-    /// Loads the item [`Model`] identified by the given name or path, as well
-    /// as all of its parents and ancestors.
+    /// Compares the textures in the given namespace of this pack to those of
+    /// `other`, treating `self` as the older of the two.
     ///
-    /// The models are returned as a list, with the first element being the
-    /// model that was originally requested, the next element being its parent,
-    /// and so on with the last element being the topmost parent.
+    /// Returns the textures that are only in `other` (the added textures),
+    /// followed by the textures that are only in `self` (the removed
+    /// textures). Each list is sorted, and the ids are in their [canonical
+    /// form]. A pack with no textures directory in the namespace is treated
+    /// as having no textures.
+    ///
+    /// [canonical form]: ResourceIdentifier::to_canonical
     ///
     /// # Example
     ///
     /// ```no_run
     /// # use minecraft_assets::api::*;
-    /// # let assets = AssetPack::at_path("foo");
-    /// let models = assets.load_item_model_recursive("item/diamond_hoe").unwrap();
+    /// let old = AssetPack::at_path("~/assets-1.14/");
+    /// let new = AssetPack::at_path("~/assets-1.15/");
     ///
-    /// let expected = vec![
-    ///     assets.load_item_model("item/diamond_hoe").unwrap(),
-    ///     assets.load_item_model("item/handheld").unwrap(),
-    ///     assets.load_item_model("item/generated").unwrap(),
-    /// ];
-    /// assert_eq!(models, expected);
+    /// let (added, removed) = old.texture_diff(&new, "minecraft").unwrap();
+    /// println!("{} textures added, {} removed", added.len(), removed.len());
     /// ```
-    pub fn load_item_model_recursive(&self, model: &str) -> Result<Vec<Model>> {
-        self.load_model_recursive(&ResourceIdentifier::item_model(model))
+    pub fn texture_diff(
+        &self,
+        other: &AssetPack,
+        namespace: &str,
+    ) -> Result<(
+        Vec<ResourceIdentifier<'static>>,
+        Vec<ResourceIdentifier<'static>>,
+    )> {
+        let textures = |pack: &AssetPack| -> Result<BTreeSet<String>> {
+            Ok(pack
+                .enumerate_namespaced(namespace, ResourceKind::Texture)?
+                .into_iter()
+                .map(|id| id.as_str().to_string())
+                .collect())
+        };
+
+        let old = textures(self)?;
+        let new = textures(other)?;
+
+        let to_ids = |locations: std::collections::btree_set::Difference<'_, String>| {
+            locations
+                .map(|location| {
+                    ResourceIdentifier::new_owned(ResourceKind::Texture, location.clone())
+                })
+                .collect()
+        };
+
+        Ok((to_ids(new.difference(&old)), to_ids(old.difference(&new))))
     }
 
-    fn load_resource<T>(&self, resource: &ResourceIdentifier) -> Result<T>
-    where
-        T: DeserializeOwned,
-    {
-        let bytes = self.provider.load_resource(resource)?;
-        Ok(serde_json::from_reader(&bytes[..])?)
+    /// Returns the ids of every resource of every [kind] in the given
+    /// namespace.
+    ///
+    /// The ids are grouped by kind, in the order of [`ResourceKind::all()`],
+    /// and sorted within each kind. Each id is in its [canonical form]. Kinds
+    /// whose directory does not exist in the namespace are skipped.
+    ///
+    /// [kind]: ResourceKind
+    /// [canonical form]: ResourceIdentifier::to_canonical
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use minecraft_assets::api::*;
+    /// # let assets = AssetPack::at_path("foo");
+    /// for id in assets.enumerate_all("minecraft").unwrap() {
+    ///     println!("{:?} {}", id.kind(), id);
+    /// }
+    /// ```
+    pub fn enumerate_all(&self, namespace: &str) -> Result<Vec<ResourceIdentifier<'static>>> {
+        let mut all = Vec::new();
+
+        for &kind in ResourceKind::all() {
+            all.extend(self.enumerate_namespaced(namespace, kind)?);
+        }
+
+        Ok(all)
     }
 
-    fn load_model_recursive(&self, resource: &ResourceIdentifier) -> Result<Vec<Model>> {
-        let mut models = Vec::new();
+    /// Returns the ids of every block and item model in the given namespace,
+    /// each tagged with its kind.
+    ///
+    /// All of the block models come first, followed by all of the item
+    /// models, and the ids of each kind are sorted. Each id is in its
+    /// [canonical form]. Kinds whose directory does not exist in the namespace
+    /// are skipped.
+    ///
+    /// Models in other subdirectories of `models/` (e.g., `models/entity/`)
+    /// are not loaded by the game, and are not listed. Those subdirectories
+    /// can be found with [`enumerate_unknown()`][Self::enumerate_unknown].
+    ///
+    /// [canonical form]: ResourceIdentifier::to_canonical
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use minecraft_assets::api::*;
+    /// # let assets = AssetPack::at_path("foo");
+    /// for (kind, id) in assets.enumerate_all_models("minecraft").unwrap() {
+    ///     let models = assets.load_model_recursive(&id).unwrap();
+    ///     println!("{:?} {} has {} ancestors", kind, id, models.len() - 1);
+    /// }
+    /// ```
+    pub fn enumerate_all_models(
+        &self,
+        namespace: &str,
+    ) -> Result<Vec<(ResourceKind, ResourceIdentifier<'static>)>> {
+        let mut all = Vec::new();
 
-        Self::for_each_parent(
-            resource.clone(),
-            |model| models.push(model),
-            |next_id| self.load_resource(next_id),
-        )?;
+        for kind in [ResourceKind::BlockModel, ResourceKind::ItemModel] {
+            let ids = self.enumerate_namespaced(namespace, kind)?;
+            all.extend(ids.into_iter().map(|id| (kind, id)));
+        }
 
-        Ok(models)
+        Ok(all)
     }
 
-    pub(crate) fn for_each_parent<F, L, E>(
-        mut current: ResourceIdentifier,
-        mut op: F,
-        mut load_model: L,
-    ) -> Result<(), E>
-    where
-        F: FnMut(Model),
-        L: FnMut(&ResourceIdentifier) -> Result<Model, E>,
-    {
-        loop {
-            let model = load_model(&current)?;
+    /// Returns a [`Manifest`] that lists every resource in the given namespace,
+    /// grouped by kind.
+    ///
+    /// Kinds with no resources in the namespace are left out of the manifest.
+    /// Each resource is listed by its [canonical] id, and the ids of each kind
+    /// are sorted. No hashes are computed; see
+    /// [`export_manifest_with_hashes()`][Self::export_manifest_with_hashes].
+    ///
+    /// [canonical]: ResourceIdentifier::to_canonical
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use minecraft_assets::api::*;
+    /// # let assets = AssetPack::at_path("foo");
+    /// let manifest = assets.export_manifest("minecraft").unwrap();
+    ///
+    /// let json = serde_json::to_string_pretty(&manifest).unwrap();
+    /// std::fs::write("manifest.json", json).unwrap();
+    /// ```
+    pub fn export_manifest(&self, namespace: &str) -> Result<Manifest> {
+        self.build_manifest(namespace, false)
+    }
 
-            let parent_owned = model.parent.clone();
+    /// Like [`export_manifest()`][Self::export_manifest], but also fills in
+    /// the [`hash`][ManifestEntry::hash] of every entry with
+    /// [`resource_hash()`][Self::resource_hash].
+    ///
+    /// Every resource in the namespace is read, so this is much slower than
+    /// [`export_manifest()`][Self::export_manifest].
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use minecraft_assets::api::*;
+    /// let old = AssetPack::at_path("old_pack").export_manifest_with_hashes("minecraft").unwrap();
+    /// let new = AssetPack::at_path("new_pack").export_manifest_with_hashes("minecraft").unwrap();
+    ///
+    /// for (kind, entries) in &new.resources {
+    ///     for entry in entries {
+    ///         let unchanged = old
+    ///             .resources
+    ///             .get(kind)
+    ///             .map_or(false, |old_entries| old_entries.contains(entry));
+    ///         if !unchanged {
+    ///             println!("{} was added or changed", entry.location);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn export_manifest_with_hashes(&self, namespace: &str) -> Result<Manifest> {
+        self.build_manifest(namespace, true)
+    }
 
-            op(model);
+    fn build_manifest(&self, namespace: &str, with_hashes: bool) -> Result<Manifest> {
+        let mut resources = BTreeMap::new();
 
-            match parent_owned {
-                Some(parent) if !ModelIdentifier::is_builtin(&parent) => {
-                    //println!("{}", parent.as_str());
-                    current = ResourceIdentifier::new_owned(current.kind(), parent);
-                }
-                _ => break,
+        for &kind in ResourceKind::ALL {
+            let ids = self.enumerate_namespaced(namespace, kind)?;
+            if ids.is_empty() {
+                continue;
             }
+
+            let entries = ids
+                .into_iter()
+                .map(|id| {
+                    let hash = if with_hashes {
+                        Some(self.resource_hash(&id)?)
+                    } else {
+                        None
+                    };
+
+                    Ok(ManifestEntry {
+                        location: id.as_str().to_string(),
+                        hash,
+                    })
+                })
+                .collect::<Result<Vec<_>>>()?;
+
+            resources.insert(kind, entries);
         }
 
-        Ok(())
+        Ok(Manifest {
+            namespace: namespace.to_string(),
+            resources,
+        })
+    }
+
+    /// Returns an iterator over all resources of the given kind in the given
+    /// namespace, along with the path of the file each one is stored in.
+    ///
+    /// Unlike [`enumerate_resources()`][Self::enumerate_resources], the
+    /// directory is walked lazily as the iterator is advanced. Files whose
+    /// names start with `_` are skipped.
+    ///
+    /// This is only supported for packs created with
+    /// [`at_path()`][Self::at_path]. Otherwise, an error of kind
+    /// [`Unsupported`][io::ErrorKind::Unsupported] is returned.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use minecraft_assets::api::*;
+    /// let assets = AssetPack::at_path("~/.minecraft/");
+    ///
+    /// let large_textures: Vec<_> = assets
+    ///     .iter_resources("minecraft", ResourceKind::Texture)
+    ///     .unwrap()
+    ///     .filter(|(_, path)| path.metadata().map_or(false, |m| m.len() > 4096))
+    ///     .collect();
+    /// ```
+    pub fn iter_resources(
+        &self,
+        namespace: &str,
+        kind: ResourceKind,
+    ) -> Result<impl Iterator<Item = (ResourceIdentifier<'static>, PathBuf)>> {
+        let root = self.root.as_ref().ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::Unsupported,
+                "resources can only be iterated in packs on the file system",
+            )
+        })?;
+
+        let directory = ResourcePath::for_kind(root, namespace, kind).into_inner();
+        let iter = ResourceIter::new(&directory, kind)?;
+
+        Ok(iter.map(move |id| {
+            // The ids yielded by `ResourceIter` are plain paths relative to
+            // `directory`, with no namespace or model prefix to strip.
+            let path = directory.join(kind.file_name(id.as_str()));
+            (id, path)
+        }))
     }
+
+    /// Enumerates the resources of the given kind in the given namespace, and
+    /// returns an iterator that loads and parses each of them as a `T`.
+    ///
+    /// Each item pairs the id of a resource, in its [canonical form], with
+    /// its parsed contents. The resources are visited in order of their ids,
+    /// and are only loaded as the iterator is advanced. A resource that fails
+    /// to load or parse yields an error without stopping the iteration.
+    ///
+    /// The resources are parsed as JSON, in the same way as by the `load_*`
+    /// methods, but they are not cached. A namespace with no resources of the
+    /// given kind yields nothing. Returns an error if the resources cannot be
+    /// enumerated (see [`enumerate_resources()`][Self::enumerate_resources]).
+    ///
+    /// [canonical form]: ResourceIdentifier::to_canonical
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use minecraft_assets::api::*;
+    /// use minecraft_assets::schemas::Model;
+    ///
+    /// # let assets = AssetPack::at_path("foo");
+    /// for item in assets.load_enumerated::<Model>("minecraft", ResourceKind::BlockModel).unwrap() {
+    ///     match item {
+    ///         Ok((id, model)) => println!("{}: {:?}", id, model.parent),
+    ///         Err(e) => eprintln!("{}", e),
+    ///     }
+    /// }
+    /// ```
+    pub fn load_enumerated<T>(
+        &self,
+        namespace: &str,
+        kind: ResourceKind,
+    ) -> Result<impl Iterator<Item = Result<(ResourceIdentifier<'static>, T)>> + '_>
+    where
+        T: DeserializeOwned,
+    {
+        let ids = self.enumerate_namespaced(namespace, kind)?;
+
+        Ok(ids.into_iter().map(move |id| {
+            let resource = self.load_resource(&id)?;
+            Ok((id, resource))
+        }))
+    }
+
+    /// Returns the directories in `assets/<namespace>/` that do not hold any
+    /// known [kind] of resource, along with their paths.
+    ///
+    /// This makes it possible to discover new types of content (e.g., `sounds`
+    /// or `shaders`) that this crate does not know about yet. Each directory
+    /// is named by its path relative to `assets/<namespace>/`, using `/` as
+    /// the separator. Directories that contain the directories of known kinds
+    /// (e.g., `models`) are searched for unknown subdirectories (e.g.,
+    /// `models/entity`) instead of being listed themselves. The directories
+    /// are sorted by name.
+    ///
+    /// This is only supported for packs created with
+    /// [`at_path()`][Self::at_path]. Otherwise, an error of kind
+    /// [`Unsupported`][io::ErrorKind::Unsupported] is returned.
+    ///
+    /// [kind]: ResourceKind
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use minecraft_assets::api::*;
+    /// let assets = AssetPack::at_path("~/.minecraft/");
+    ///
+    /// for (name, path) in assets.enumerate_unknown("minecraft").unwrap() {
+    ///     println!("unknown directory `{}` at {}", name, path.display());
+    /// }
+    /// ```
+    pub fn enumerate_unknown(&self, namespace: &str) -> Result<Vec<(String, PathBuf)>> {
+        let root = self.root.as_ref().ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::Unsupported,
+                "directories can only be enumerated in packs on the file system",
+            )
+        })?;
+
+        let known: Vec<&str> = ResourceKind::all()
+            .iter()
+            .filter(|kind| kind.category() == ResourceCategory::Assets)
+            .map(|kind| kind.directory())
+            .collect();
+
+        let directory = root
+            .join(ResourceCategory::Assets.directory())
+            .join(namespace);
+
+        let mut unknown = Vec::new();
+        find_unknown_directories(&directory, "", &known, &mut unknown)?;
+        unknown.sort();
+
+        Ok(unknown)
+    }
+
+    /// Returns an owned [`ResourceIdentifier`] of the given kind for the given
+    /// id.
+    ///
+    /// This is a shorthand for [`ResourceIdentifier::new_owned()`] that is
+    /// convenient when the kind of resource is only known at runtime.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::api::*;
+    /// # let assets = AssetPack::at_path("foo");
+    /// let id = assets.location(ResourceKind::Texture, "block/stone");
+    ///
+    /// assert_eq!(id, ResourceIdentifier::texture("block/stone").to_owned());
+    /// ```
+    pub fn location(&self, kind: ResourceKind, id: &str) -> ResourceIdentifier<'static> {
+        ResourceIdentifier::new_owned(kind, String::from(id))
+    }
+
+    /// Returns `true` if the resource referenced by the given
+    /// [`ResourceIdentifier`] exists in the pack.
+    ///
+    /// The resource is not loaded or parsed. See
+    /// [`LoadResource::contains()`][crate::api::LoadResource::contains].
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use minecraft_assets::api::*;
+    /// # let assets = AssetPack::at_path("foo");
+    /// let exists = assets.resource_exists(&ResourceIdentifier::texture("block/stone"));
+    /// ```
+    pub fn resource_exists(&self, id: &ResourceIdentifier) -> bool {
+        self.provider.contains(id)
+    }
+
+    /// Returns a hash of the raw bytes of the resource referenced by the given
+    /// [`ResourceIdentifier`].
+    ///
+    /// The hash is computed with a fixed, non-cryptographic algorithm (64-bit
+    /// FNV-1a), so it is the same across runs, platforms, and versions of this
+    /// crate. Two resources with the same hash very likely have the same
+    /// contents.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use minecraft_assets::api::*;
+    /// let old = AssetPack::at_path("old_pack");
+    /// let new = AssetPack::at_path("new_pack");
+    ///
+    /// let id = ResourceIdentifier::texture("block/stone");
+    /// if old.resource_hash(&id).unwrap() != new.resource_hash(&id).unwrap() {
+    ///     println!("the stone texture changed");
+    /// }
+    /// ```
+    pub fn resource_hash(&self, id: &ResourceIdentifier) -> Result<u64> {
+        let bytes = self.load_bytes(id)?;
+        Ok(fnv1a_hash(&bytes))
+    }
+
+    /// Returns `true` if the [`BlockStates`] of the block with the provided id
+    /// exist in the pack.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use minecraft_assets::api::*;
+    /// # let assets = AssetPack::at_path("foo");
+    /// let exists = assets.has_blockstates("stone");
+    /// let exists = assets.has_blockstates("minecraft:dirt");
+    /// ```
+    pub fn has_blockstates(&self, block_id: &str) -> bool {
+        self.resource_exists(&ResourceIdentifier::blockstates(block_id))
+    }
+
+    /// Returns `true` if the block [`Model`] identified by the given name or
+    /// path exists in the pack.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use minecraft_assets::api::*;
+    /// # let assets = AssetPack::at_path("foo");
+    /// let exists = assets.has_block_model("stone");
+    /// let exists = assets.has_block_model("block/dirt");
+    /// ```
+    pub fn has_block_model(&self, model: &str) -> bool {
+        self.resource_exists(&ResourceIdentifier::block_model(model))
+    }
+
+    /// Loads the [`BlockStates`] of the block with the provided id.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use minecraft_assets::api::*;
+    /// # let assets = AssetPack::at_path("foo");
+    /// let states = assets.load_blockstates("stone");
+    /// let states = assets.load_blockstates("minecraft:dirt");
+    /// ```
+    pub fn load_blockstates(&self, block_id: &str) -> Result<BlockStates> {
+        let states: BlockStates = self.load_resource(&ResourceIdentifier::blockstates(block_id))?;
+
+        if self.strict {
+            states
+                .validate()
+                .map_err(|value| Error::InvalidRotation { value })?;
+        }
+
+        Ok(states)
+    }
+
+    /// Loads the raw contents of the [`BlockStates`] file of the block with
+    /// the provided id, without parsing them.
+    ///
+    /// The bytes can be parsed later with [`BlockStates::from_slice()`].
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use minecraft_assets::api::*;
+    /// use minecraft_assets::schemas::BlockStates;
+    ///
+    /// # let assets = AssetPack::at_path("foo");
+    /// let bytes = assets.load_blockstates_bytes("stone").unwrap();
+    /// println!("{}", String::from_utf8_lossy(&bytes));
+    ///
+    /// let states = BlockStates::from_slice(&bytes).unwrap();
+    /// ```
+    pub fn load_blockstates_bytes(&self, block_id: &str) -> Result<Vec<u8>> {
+        self.load_bytes(&ResourceIdentifier::blockstates(block_id))
+    }
+
+    /// Loads the block [`Model`] identified by the given name or path.
+    ///
+    /// The model is only ever read from `assets/<namespace>/models/block/`. If
+    /// no such file exists, an error is returned, even if an item model of the
+    /// same name exists in `models/item/`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use minecraft_assets::api::*;
+    /// # let assets = AssetPack::at_path("foo");
+    /// let model = assets.load_block_model("stone");
+    /// let model = assets.load_block_model("block/dirt");
+    /// ```
+    pub fn load_block_model(&self, model: &str) -> Result<Model> {
+        self.load_model(&ResourceIdentifier::block_model(model))
+    }
+
+    /// Loads the block [`Model`] identified by the given name or path, as well
+    /// as all of its parents and ancestors.
+    ///
+    /// The models are returned as a list, with the first element being the
+    /// model that was originally requested, the next element being its parent,
+    /// and so on with the last element being the topmost parent.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use minecraft_assets::api::*;
+    /// # let assets = AssetPack::at_path("foo");
+    /// let models = assets.load_block_model_recursive("block/cube_all").unwrap();
+    ///
+    /// let expected = vec![
+    ///     assets.load_block_model("block/cube_all").unwrap(),
+    ///     assets.load_block_model("block/cube").unwrap(),
+    ///     assets.load_block_model("block/block").unwrap(),
+    /// ];
+    /// assert_eq!(models, expected);
+    /// ```
+    pub fn load_block_model_recursive(&self, model: &str) -> Result<Vec<Model>> {
+        self.load_model_recursive(&ResourceIdentifier::block_model(model))
+    }
+
+    /// Like [`load_block_model_recursive()`][Self::load_block_model_recursive],
+    /// but stores the models in the provided buffer instead of allocating a
+    /// new one.
+    ///
+    /// The buffer is cleared first. If an error is returned, the buffer holds
+    /// the models that were loaded before the error occurred.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use minecraft_assets::api::*;
+    /// # let assets = AssetPack::at_path("foo");
+    /// let mut models = Vec::new();
+    ///
+    /// for block in ["stone", "dirt", "oak_planks"] {
+    ///     assets.load_block_model_recursive_into(block, &mut models).unwrap();
+    ///     println!("{} has {} ancestors", block, models.len() - 1);
+    /// }
+    /// ```
+    pub fn load_block_model_recursive_into(&self, model: &str, buf: &mut Vec<Model>) -> Result<()> {
+        self.load_model_recursive_into(&ResourceIdentifier::block_model(model), buf)
+    }
+
+    /// Loads the block [`Model`] identified by the given name or path, and
+    /// flattens it together with all of its parents and ancestors into a
+    /// single, self-contained [`Model`].
+    ///
+    /// The returned model has `parent` set to `None`. Its fields are resolved
+    /// with [`ModelResolver::resolve_model()`], where values in a child model
+    /// take precedence over values in its parent:
+    ///
+    /// * `textures` are merged from all models in the chain, with child
+    ///   variables overriding parent variables, and texture variable
+    ///   references are substituted wherever possible.
+    /// * `elements`, `display`, `ambientocclusion`, and `gui_light` are taken
+    ///   from the first model in the chain (starting at the requested model)
+    ///   that defines them. Texture references in the `elements` are
+    ///   substituted with the resolved textures.
+    /// * `overrides` are only taken from the requested model.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use minecraft_assets::api::*;
+    /// # let assets = AssetPack::at_path("foo");
+    /// let model = assets.load_block_model_resolved("block/cube_all").unwrap();
+    ///
+    /// assert!(model.parent.is_none());
+    /// assert!(model.elements.is_some());
+    /// ```
+    pub fn load_block_model_resolved(&self, model: &str) -> Result<Model> {
+        let models = self.load_block_model_recursive(model)?;
+        Ok(ModelResolver::resolve_model(models.iter()))
+    }
+
+    /// Loads and fully resolves the block model referenced by the given
+    /// [`ModelProperties`], and packages it together with the rotation it
+    /// should be placed with.
+    ///
+    /// The model is resolved the same way as in
+    /// [`load_block_model_resolved()`][Self::load_block_model_resolved].
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use minecraft_assets::api::*;
+    /// # let assets = AssetPack::at_path("foo");
+    /// let states = assets.load_blockstates("oak_log").unwrap();
+    /// let props = &states.variants().unwrap()["axis=x"].models()[0];
+    ///
+    /// let placed = assets.place_model(props).unwrap();
+    /// assert_eq!(placed.x, 90);
+    /// assert_eq!(placed.y, 90);
+    /// ```
+    pub fn place_model(&self, props: &ModelProperties) -> Result<PlacedModel> {
+        let model = self.load_block_model_resolved(&props.model)?;
+
+        Ok(PlacedModel {
+            model,
+            x: props.x,
+            y: props.y,
+            uv_lock: props.uv_lock,
+        })
+    }
+
+    /// Loads the [`BlockStates`] of the block with the provided id, and places
+    /// every model that they reference with
+    /// [`place_model()`][Self::place_model].
+    ///
+    /// The result has the same shape as the block states: blocks defined with
+    /// [`Variants`][BlockStates::Variants] map each variant name to its
+    /// models, and [`Multipart`][BlockStates::Multipart] blocks list the
+    /// models of each case. A variant or case with several weighted models
+    /// yields all of them, in order.
+    ///
+    /// Each distinct model is only resolved once, even if several variants
+    /// refer to it.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use minecraft_assets::api::*;
+    /// # let assets = AssetPack::at_path("foo");
+    /// let resolved = assets.load_blockstates_resolved("oak_log").unwrap();
+    ///
+    /// let variants = resolved.variants().unwrap();
+    /// let placed = &variants["axis=x"][0];
+    /// assert_eq!(placed.x, 90);
+    /// assert!(placed.model.textures.is_some());
+    /// ```
+    pub fn load_blockstates_resolved(&self, block_id: &str) -> Result<ResolvedBlockStates> {
+        let states = self.load_blockstates(block_id)?;
+
+        let mut resolved: HashMap<String, Model> = HashMap::new();
+        let mut place_all = |variant: &Variant| -> Result<Vec<PlacedModel>> {
+            variant
+                .models()
+                .iter()
+                .map(|props| {
+                    let model = match resolved.get(&props.model) {
+                        Some(model) => model.clone(),
+                        None => {
+                            let model = self.load_block_model_resolved(&props.model)?;
+                            resolved.insert(props.model.clone(), model.clone());
+                            model
+                        }
+                    };
+
+                    Ok(PlacedModel {
+                        model,
+                        x: props.x,
+                        y: props.y,
+                        uv_lock: props.uv_lock,
+                    })
+                })
+                .collect()
+        };
+
+        match states {
+            BlockStates::Variants { variants } => {
+                let variants = variants
+                    .iter()
+                    .map(|(name, variant)| Ok((name.clone(), place_all(variant)?)))
+                    .collect::<Result<_>>()?;
+
+                Ok(ResolvedBlockStates::Variants(variants))
+            }
+            BlockStates::Multipart { cases } => {
+                let cases = cases
+                    .into_iter()
+                    .map(|case| {
+                        Ok(ResolvedCase {
+                            models: place_all(&case.apply)?,
+                            when: case.when,
+                        })
+                    })
+                    .collect::<Result<_>>()?;
+
+                Ok(ResolvedBlockStates::Multipart(cases))
+            }
+        }
+    }
+
+    /// Loads the item [`Model`] identified by the given name or path.
+    ///
+    /// The model is only ever read from `assets/<namespace>/models/item/`. If
+    /// no such file exists, an error is returned, even if a block model of the
+    /// same name exists in `models/block/`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use minecraft_assets::api::*;
+    /// # let assets = AssetPack::at_path("foo");
+    /// let model = assets.load_item_model("compass");
+    /// let model = assets.load_item_model("item/diamond_hoe");
+    /// ```
+    pub fn load_item_model(&self, model: &str) -> Result<Model> {
+        self.load_model(&ResourceIdentifier::item_model(model))
+    }
+
+    /// Loads the item [`Model`] identified by the given name or path, as well
+    /// as all of its parents and ancestors.
+    ///
+    /// The models are returned as a list, with the first element being the
+    /// model that was originally requested, the next element being its parent,
+    /// and so on with the last element being the topmost parent.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use minecraft_assets::api::*;
+    /// # let assets = AssetPack::at_path("foo");
+    /// let models = assets.load_item_model_recursive("item/diamond_hoe").unwrap();
+    ///
+    /// let expected = vec![
+    ///     assets.load_item_model("item/diamond_hoe").unwrap(),
+    ///     assets.load_item_model("item/handheld").unwrap(),
+    ///     assets.load_item_model("item/generated").unwrap(),
+    /// ];
+    /// assert_eq!(models, expected);
+    /// ```
+    pub fn load_item_model_recursive(&self, model: &str) -> Result<Vec<Model>> {
+        self.load_model_recursive(&ResourceIdentifier::item_model(model))
+    }
+
+    /// Loads the item [`Model`] identified by the given name or path, and
+    /// fully resolves the model of each of its [overrides].
+    ///
+    /// Each override is returned as its predicate, with every value converted
+    /// to an `f64`, paired with the resolved model that is used when the
+    /// predicate is met. The overrides are returned in the order that they
+    /// are listed in the item model. Only the overrides of the item model
+    /// itself are used, not those of its parents. The referenced models are
+    /// resolved the same way as in
+    /// [`load_block_model_resolved()`][Self::load_block_model_resolved].
+    ///
+    /// [overrides]: Model::overrides
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use minecraft_assets::api::*;
+    /// # let assets = AssetPack::at_path("foo");
+    /// for (predicate, model) in assets.resolve_item_overrides("bow").unwrap() {
+    ///     let pull = predicate.get("pull").copied().unwrap_or(0.0);
+    ///     println!("at pull {}: {:?}", pull, model.textures);
+    /// }
+    /// ```
+    pub fn resolve_item_overrides(
+        &self,
+        item_id: &str,
+    ) -> Result<Vec<(HashMap<String, f64>, Model)>> {
+        let model = self.load_item_model(item_id)?;
+
+        model
+            .overrides
+            .iter()
+            .flatten()
+            .map(|case| {
+                let predicate = case
+                    .predicate
+                    .iter()
+                    .map(|(name, value)| (name.clone(), value.as_f64()))
+                    .collect();
+
+                let id = ResourceIdentifier::new_owned(ResourceKind::ItemModel, case.model.clone());
+                let models = self.load_model_recursive(&id)?;
+
+                Ok((predicate, ModelResolver::resolve_model(models.iter())))
+            })
+            .collect()
+    }
+
+    /// Loads the block or item [`Model`] referenced by the given
+    /// [`ResourceIdentifier`], as well as all of its parents and ancestors.
+    ///
+    /// This is like [`load_block_model_recursive()`] and
+    /// [`load_item_model_recursive()`], but is useful when the id has already
+    /// been built, e.g., by [`enumerate_resources()`]. An error of kind
+    /// [`InvalidInput`][io::ErrorKind::InvalidInput] is returned if the id
+    /// does not refer to a model.
+    ///
+    /// [`load_block_model_recursive()`]: Self::load_block_model_recursive
+    /// [`load_item_model_recursive()`]: Self::load_item_model_recursive
+    /// [`enumerate_resources()`]: Self::enumerate_resources
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use minecraft_assets::api::*;
+    /// # let assets = AssetPack::at_path("foo");
+    /// let id = ResourceIdentifier::item_model("diamond_hoe");
+    /// let models = assets.load_model_recursive(&id).unwrap();
+    ///
+    /// assert_eq!(models, assets.load_item_model_recursive("diamond_hoe").unwrap());
+    /// ```
+    pub fn load_model_recursive(&self, id: &ResourceIdentifier) -> Result<Vec<Model>> {
+        Self::check_model_kind(id.kind())?;
+
+        let mut models = Vec::new();
+        self.load_model_recursive_into(id, &mut models)?;
+
+        Ok(models)
+    }
+
+    /// Loads every model of the given kind ([`BlockModel`] or [`ItemModel`])
+    /// in the given namespace.
+    ///
+    /// The result of loading each model is returned individually, so one
+    /// broken file does not prevent the others from being loaded. The outer
+    /// [`Result`] is only an error if the models could not be enumerated, or
+    /// if `kind` is not a model kind.
+    ///
+    /// The returned ids are in their [canonical form].
+    ///
+    /// [`BlockModel`]: ResourceKind::BlockModel
+    /// [`ItemModel`]: ResourceKind::ItemModel
+    /// [canonical form]: ResourceIdentifier::to_canonical
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use minecraft_assets::api::*;
+    /// # let assets = AssetPack::at_path("foo");
+    /// let models = assets
+    ///     .load_all_models("minecraft", ResourceKind::BlockModel)
+    ///     .unwrap();
+    ///
+    /// for (id, model) in models {
+    ///     if let Err(e) = model {
+    ///         println!("{} is broken: {}", id, e);
+    ///     }
+    /// }
+    /// ```
+    pub fn load_all_models(
+        &self,
+        namespace: &str,
+        kind: ResourceKind,
+    ) -> Result<Vec<(ResourceIdentifier<'static>, Result<Model>)>> {
+        Self::check_model_kind(kind)?;
+
+        let ids = self.enumerate_namespaced(namespace, kind)?;
+
+        Ok(ids
+            .into_iter()
+            .map(|id| {
+                let model = self.load_model(&id);
+                (id, model)
+            })
+            .collect())
+    }
+
+    /// Returns every texture used by the given block or item model, once its
+    /// texture variables have been resolved against its parents.
+    ///
+    /// This includes the textures assigned to the model's texture variables
+    /// (including `particle`) and those referenced directly by the faces of
+    /// its elements. Variables that are left unresolved (e.g., `#missing`) are
+    /// skipped.
+    ///
+    /// The returned ids are [`Texture`] ids in their [canonical form].
+    ///
+    /// [`Texture`]: ResourceKind::Texture
+    /// [canonical form]: ResourceIdentifier::to_canonical
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use minecraft_assets::api::*;
+    /// # let assets = AssetPack::at_path("foo");
+    /// let textures = assets
+    ///     .collect_model_textures(ResourceIdentifier::block_model("grass_block"))
+    ///     .unwrap();
+    ///
+    /// assert!(textures.contains(&ResourceIdentifier::texture("block/grass_block_top")));
+    /// ```
+    pub fn collect_model_textures<'a>(
+        &self,
+        model: impl Into<ResourceIdentifier<'a>>,
+    ) -> Result<HashSet<ResourceIdentifier<'static>>> {
+        let models = self.load_model_recursive(&model.into())?;
+
+        let textures = ModelResolver::resolve_textures(models.iter());
+        let mut elements = ModelResolver::resolve_elements(models.iter()).unwrap_or_default();
+        ModelResolver::resolve_element_textures(&mut elements, &textures);
+
+        let variables = textures.iter().map(|(_, texture)| texture);
+        let faces = elements
+            .iter()
+            .flat_map(|element| element.faces.values())
+            .map(|face| &face.texture);
+
+        Ok(variables
+            .chain(faces)
+            .filter_map(Texture::location)
+            .map(|location| {
+                ResourceIdentifier::texture(location)
+                    .to_canonical()
+                    .to_owned()
+            })
+            .collect())
+    }
+
+    /// Loads every block model in the given namespace, reading and parsing
+    /// the models in parallel on [`rayon`]'s global thread pool.
+    ///
+    /// Providers that can only read one file at a time (like the one for zip
+    /// archives) serialize the reads themselves, so only the parsing runs in
+    /// parallel for them.
+    ///
+    /// If `fail_fast` is `true`, the first error encountered is returned.
+    /// Otherwise, every model that could be loaded is returned, along with
+    /// the errors for those that could not. In both cases, the outer
+    /// [`Result`] is an error if the models could not be enumerated.
+    ///
+    /// The returned ids are in their [canonical form].
+    ///
+    /// **Requires the `rayon` feature.**
+    ///
+    /// [canonical form]: ResourceIdentifier::to_canonical
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use minecraft_assets::api::*;
+    /// # let assets = AssetPack::at_path("foo");
+    /// let loaded = assets.load_all_block_models("minecraft", false).unwrap();
+    ///
+    /// println!("loaded {} models", loaded.models.len());
+    /// for (id, e) in &loaded.errors {
+    ///     println!("{} is broken: {}", id, e);
+    /// }
+    /// ```
+    #[cfg(feature = "rayon")]
+    pub fn load_all_block_models(&self, namespace: &str, fail_fast: bool) -> Result<LoadedModels> {
+        use rayon::prelude::*;
+
+        let ids = self.enumerate_namespaced(namespace, ResourceKind::BlockModel)?;
+
+        let load = |id: &ResourceIdentifier| -> Result<Model> {
+            let bytes = self.load_bytes(id)?;
+            Self::parse_bytes(self.root.as_deref(), self.lenient, id, &bytes)
+        };
+
+        if fail_fast {
+            let models = ids
+                .into_par_iter()
+                .map(|id| {
+                    let model = load(&id)?;
+                    Ok((id, model))
+                })
+                .collect::<Result<_>>()?;
+
+            return Ok(LoadedModels {
+                models,
+                errors: Vec::new(),
+            });
+        }
+
+        let results: Vec<_> = ids
+            .into_par_iter()
+            .map(|id| {
+                let model = load(&id);
+                (id, model)
+            })
+            .collect();
+
+        let mut loaded = LoadedModels::default();
+        for (id, result) in results {
+            match result {
+                Ok(model) => {
+                    loaded.models.insert(id, model);
+                }
+                Err(e) => loaded.errors.push((id, e)),
+            }
+        }
+
+        Ok(loaded)
+    }
+
+    /// Finds the textures referenced by the block and item models in the given
+    /// namespace that do not exist in the pack.
+    ///
+    /// Each model's texture variables are resolved against its parents, and
+    /// every resulting texture is looked up with the provider's
+    /// [`contains()`][crate::api::LoadResource::contains]. Variables that
+    /// remain unresolved are not reported, and neither are models that fail
+    /// to load.
+    ///
+    /// Returns the [canonical] id of each offending model along with the
+    /// texture it references.
+    ///
+    /// [canonical]: ResourceIdentifier::to_canonical
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use minecraft_assets::api::*;
+    /// # let assets = AssetPack::at_path("foo");
+    /// for (model, texture) in assets.find_dangling_texture_refs("minecraft").unwrap() {
+    ///     println!("{} references missing texture {}", model, texture);
+    /// }
+    /// ```
+    pub fn find_dangling_texture_refs(
+        &self,
+        namespace: &str,
+    ) -> Result<Vec<(ResourceIdentifier<'static>, String)>> {
+        let mut dangling = Vec::new();
+
+        for kind in [ResourceKind::BlockModel, ResourceKind::ItemModel] {
+            for id in self.enumerate_namespaced(namespace, kind)? {
+                let chain = match self.load_model_recursive(&id) {
+                    Ok(chain) => chain,
+                    Err(_) => continue,
+                };
+
+                let textures = ModelResolver::resolve_textures(chain.iter());
+                let locations: BTreeSet<&str> = textures
+                    .iter()
+                    .filter_map(|(_, texture)| texture.location())
+                    .collect();
+
+                for location in locations {
+                    if !self
+                        .provider
+                        .contains(&ResourceIdentifier::texture(location))
+                    {
+                        dangling.push((id.clone(), location.to_string()));
+                    }
+                }
+            }
+        }
+
+        Ok(dangling)
+    }
+
+    /// Finds the models referenced by the block states in the given namespace
+    /// that do not exist in the pack.
+    ///
+    /// Every block states file is checked, whether it is specified as
+    /// [`Variants`][BlockStates::Variants] or as
+    /// [`Multipart`][BlockStates::Multipart] cases. Each missing model is
+    /// reported once per block states file. Block states that cannot be loaded
+    /// are skipped.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use minecraft_assets::api::*;
+    /// # let assets = AssetPack::at_path("foo");
+    /// for issue in assets.validate_blockstate_references("minecraft").unwrap() {
+    ///     println!("{} references missing model {}", issue.blockstates, issue.model);
+    /// }
+    /// ```
+    pub fn validate_blockstate_references(&self, namespace: &str) -> Result<Vec<ValidationIssue>> {
+        let mut issues = Vec::new();
+
+        for id in self.enumerate_namespaced(namespace, ResourceKind::BlockStates)? {
+            let states: BlockStates = match self.load_resource(&id) {
+                Ok(states) => states,
+                Err(_) => continue,
+            };
+
+            let models: BTreeSet<&str> = states
+                .all_models()
+                .map(|props| props.model.as_str())
+                .collect();
+
+            for model in models {
+                if !self.has_block_model(model) {
+                    issues.push(ValidationIssue {
+                        blockstates: id.clone(),
+                        model: model.to_string(),
+                    });
+                }
+            }
+        }
+
+        Ok(issues)
+    }
+
+    /// Finds the textures in the given namespace whose dimensions do not match
+    /// how they are animated.
+    ///
+    /// A texture with a `.png.mcmeta` file that has an `animation` section is
+    /// split into frames. The size of each frame is given by the `width` and
+    /// `height` of the animation, with a missing value defaulting to the size
+    /// of the texture. If neither is given, the frames are squares as wide as
+    /// the narrower side of the texture. A texture that is not a whole number
+    /// of frames wide and tall is reported as
+    /// [`PartialFrames`][TextureIssueKind::PartialFrames].
+    ///
+    /// A texture in `textures/block/` without an animation is reported as
+    /// [`NotSquare`][TextureIssueKind::NotSquare] if it is not square, since
+    /// such a texture usually holds animation frames but is missing its
+    /// `.png.mcmeta` file.
+    ///
+    /// The issues are sorted by texture. Textures that cannot be read or are
+    /// not valid PNG files, and textures whose `.png.mcmeta` files cannot be
+    /// parsed, are skipped.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use minecraft_assets::api::*;
+    /// # let assets = AssetPack::at_path("foo");
+    /// for issue in assets.validate_texture_dimensions("minecraft").unwrap() {
+    ///     println!(
+    ///         "{} ({}x{}): {:?}",
+    ///         issue.texture, issue.width, issue.height, issue.kind
+    ///     );
+    /// }
+    /// ```
+    pub fn validate_texture_dimensions(&self, namespace: &str) -> Result<Vec<TextureIssue>> {
+        /// The parts of a `.png.mcmeta` file that determine the frame size.
+        #[derive(Deserialize)]
+        struct TextureMeta {
+            animation: Option<Animation>,
+        }
+
+        #[derive(Deserialize)]
+        struct Animation {
+            width: Option<u32>,
+            height: Option<u32>,
+        }
+
+        let mut issues = Vec::new();
+
+        for texture in self.enumerate_namespaced(namespace, ResourceKind::Texture)? {
+            let bytes = match self.load_bytes(&texture) {
+                Ok(bytes) => bytes,
+                Err(_) => continue,
+            };
+            let (width, height) = match png_dimensions(&bytes) {
+                Some(dimensions) => dimensions,
+                None => continue,
+            };
+
+            let meta_id = ResourceIdentifier::new(ResourceKind::TextureMeta, texture.as_str());
+            let animation = if self.contains(&meta_id) {
+                match self.load_resource::<TextureMeta>(&meta_id) {
+                    Ok(meta) => meta.animation,
+                    Err(_) => continue,
+                }
+            } else {
+                None
+            };
+
+            let kind = match animation {
+                Some(animation) => {
+                    let (frame_width, frame_height) = match (animation.width, animation.height) {
+                        (None, None) => (width.min(height), width.min(height)),
+                        (frame_width, frame_height) => {
+                            (frame_width.unwrap_or(width), frame_height.unwrap_or(height))
+                        }
+                    };
+
+                    let whole_frames = frame_width != 0
+                        && frame_height != 0
+                        && width % frame_width == 0
+                        && height % frame_height == 0;
+                    if whole_frames {
+                        continue;
+                    }
+
+                    TextureIssueKind::PartialFrames {
+                        frame_width,
+                        frame_height,
+                    }
+                }
+                None if width != height && texture.path().starts_with("block/") => {
+                    TextureIssueKind::NotSquare
+                }
+                None => continue,
+            };
+
+            issues.push(TextureIssue {
+                texture,
+                width,
+                height,
+                kind,
+            });
+        }
+
+        Ok(issues)
+    }
+
+    /// Returns the fully-resolved [`Model`]s used to render the given block in
+    /// the given state, each with the rotation it should be rendered with.
+    ///
+    /// The block's [`BlockStates`] are loaded and every model that applies to
+    /// `state` is placed with [`place_model()`][Self::place_model], which
+    /// carries over the `x`, `y`, and `uvlock` of its [`ModelProperties`].
+    /// Blocks defined with [`Variants`][BlockStates::Variants] yield one
+    /// model, while [`Multipart`][BlockStates::Multipart] blocks yield one
+    /// model for each case that applies, in order. The models of a multipart
+    /// block should all be rendered, stacked on top of each other (e.g., the
+    /// post and the sides of a fence).
+    ///
+    /// When a variant lists several weighted models, the one the game is most
+    /// likely to pick (the one with the highest weight, or the first listed of
+    /// those tied for it) is always used, so the result is the same on every
+    /// call and can be cached. To pick one at random the way the game does,
+    /// load the [`BlockStates`] and place the model chosen by
+    /// `Variant::choose_weighted()` (with the `rand` feature) instead.
+    ///
+    /// If caching was enabled with
+    /// [`with_resolve_cache()`][Self::with_resolve_cache], the result is cached
+    /// by block id and state.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use minecraft_assets::api::*;
+    /// use minecraft_assets::schemas::blockstates::BlockStates;
+    ///
+    /// # let assets = AssetPack::at_path("foo");
+    /// let state = BlockStates::parse_variant_key("axis=y");
+    /// let models = assets.resolve_block("oak_log", &state).unwrap();
+    /// assert_eq!(models.len(), 1);
+    ///
+    /// let state = BlockStates::parse_variant_key("east=low,south=tall,up=true");
+    /// let models = assets.resolve_block("cobblestone_wall", &state).unwrap();
+    /// assert_eq!(models.len(), 3);
+    /// ```
+    pub fn resolve_block(
+        &self,
+        block_id: &str,
+        state: &BlockStateProperties,
+    ) -> Result<Vec<PlacedModel>> {
+        let cache = match self.resolve_cache {
+            Some(ref cache) => cache,
+            None => return self.resolve_block_uncached(block_id, state),
+        };
+
+        let key = (
+            ResourceIdentifier::blockstates(block_id)
+                .to_canonical()
+                .to_string(),
+            state.to_key(),
+        );
+
+        if let Some(models) = cache.get(&key) {
+            return Ok(models);
+        }
+
+        let models = self.resolve_block_uncached(block_id, state)?;
+        cache.insert(key, models.clone());
+
+        Ok(models)
+    }
+
+    fn resolve_block_uncached(
+        &self,
+        block_id: &str,
+        state: &BlockStateProperties,
+    ) -> Result<Vec<PlacedModel>> {
+        let state: HashMap<String, String> = state.properties.clone().into_iter().collect();
+
+        self.load_blockstates(block_id)?
+            .active_variants(&state)
+            .into_iter()
+            // `max_by_key()` returns the last of several maximums, so search
+            // backwards to prefer the first one listed.
+            .filter_map(|variant| {
+                variant
+                    .models()
+                    .iter()
+                    .rev()
+                    .max_by_key(|properties| properties.weight)
+            })
+            .map(|properties| self.place_model(properties))
+            .collect()
+    }
+
+    /// Like [`load_item_model_recursive()`], but if the topmost parent in the
+    /// chain inherits from a builtin model (like `builtin/generated` or
+    /// `builtin/entity`), a final [`ModelChainEntry::Builtin`] entry is
+    /// appended to the chain.
+    ///
+    /// Builtin models have no corresponding file, so this entry only carries
+    /// the builtin's name (e.g., `"generated"`). It tells the consumer which
+    /// renderer the chain ultimately relies on.
+    ///
+    /// [`load_item_model_recursive()`]: Self::load_item_model_recursive
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use minecraft_assets::api::*;
+    /// # let assets = AssetPack::at_path("foo");
+    /// let chain = assets
+    ///     .load_item_model_recursive_with_builtin("item/diamond_hoe")
+    ///     .unwrap();
+    ///
+    /// let expected = vec![
+    ///     ModelChainEntry::Model(assets.load_item_model("item/diamond_hoe").unwrap()),
+    ///     ModelChainEntry::Model(assets.load_item_model("item/handheld").unwrap()),
+    ///     ModelChainEntry::Model(assets.load_item_model("item/generated").unwrap()),
+    ///     ModelChainEntry::Builtin(String::from("generated")),
+    /// ];
+    /// assert_eq!(chain, expected);
+    /// ```
+    pub fn load_item_model_recursive_with_builtin(
+        &self,
+        model: &str,
+    ) -> Result<Vec<ModelChainEntry>> {
+        let models = self.load_item_model_recursive(model)?;
+
+        let builtin = models
+            .last()
+            .and_then(|model| model.parent.as_deref())
+            .filter(|parent| ModelIdentifier::is_builtin(parent))
+            .map(|parent| String::from(ModelIdentifier::model_name(parent)));
+
+        let mut chain: Vec<ModelChainEntry> =
+            models.into_iter().map(ModelChainEntry::Model).collect();
+        chain.extend(builtin.map(ModelChainEntry::Builtin));
+
+        Ok(chain)
+    }
+
+    /// Loads the [`FontDefinition`] with the given name from
+    /// `assets/<namespace>/font/`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use minecraft_assets::api::*;
+    /// # let assets = AssetPack::at_path("foo");
+    /// let font = assets.load_font("minecraft", "default");
+    /// let font = assets.load_font("minecraft", "alt");
+    /// ```
+    pub fn load_font(&self, namespace: &str, name: &str) -> Result<FontDefinition> {
+        let id = format!("{}:{}", namespace, name);
+        self.load_resource(&ResourceIdentifier::new_owned(ResourceKind::Font, id))
+    }
+
+    /// Loads the [`ParticleDefinition`] with the given name from
+    /// `assets/<namespace>/particles/`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use minecraft_assets::api::*;
+    /// # let assets = AssetPack::at_path("foo");
+    /// let particle = assets.load_particle("minecraft", "flame");
+    /// ```
+    pub fn load_particle(&self, namespace: &str, name: &str) -> Result<ParticleDefinition> {
+        let id = format!("{}:{}", namespace, name);
+        self.load_resource(&ResourceIdentifier::new_owned(ResourceKind::Particle, id))
+    }
+
+    /// Loads the [`AtlasDefinition`] with the given name from
+    /// `assets/<namespace>/atlases/`.
+    ///
+    /// Atlas files only exist in packs for Minecraft 1.19.3 and later.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use minecraft_assets::api::*;
+    /// # let assets = AssetPack::at_path("foo");
+    /// let atlas = assets.load_atlas("minecraft", "blocks");
+    /// ```
+    pub fn load_atlas(&self, namespace: &str, name: &str) -> Result<AtlasDefinition> {
+        let id = format!("{}:{}", namespace, name);
+        self.load_resource(&ResourceIdentifier::new_owned(ResourceKind::Atlas, id))
+    }
+
+    /// Loads the OptiFine [`Properties`] file with the given path from
+    /// `assets/<namespace>/optifine/`.
+    ///
+    /// The path is relative to the `optifine/` directory and leaves out the
+    /// `.properties` extension.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use minecraft_assets::api::*;
+    /// # let assets = AssetPack::at_path("foo");
+    /// let properties = assets.load_optifine_properties("minecraft", "ctm/glass/glass");
+    /// ```
+    pub fn load_optifine_properties(&self, namespace: &str, path: &str) -> Result<Properties> {
+        let id = format!("{}:{}", namespace, path);
+        let bytes = self.load_bytes(&ResourceIdentifier::new_owned(
+            ResourceKind::OptifineProperties,
+            id,
+        ))?;
+
+        Ok(Properties::from_slice(&bytes))
+    }
+
+    /// Loads the [`Recipe`] with the provided id from
+    /// `data/<namespace>/recipes/`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use minecraft_assets::api::*;
+    /// # let assets = AssetPack::at_path("foo");
+    /// let recipe = assets.load_recipe("oak_planks");
+    /// let recipe = assets.load_recipe("minecraft:iron_ingot_from_smelting_iron_ore");
+    /// ```
+    pub fn load_recipe(&self, recipe_id: &str) -> Result<Recipe> {
+        self.load_resource(&ResourceIdentifier::recipe(recipe_id))
+    }
+
+    /// Loads the [`Tag`] of the given type (e.g., `"blocks"` or `"items"`)
+    /// with the provided id from `data/<namespace>/tags/<tag_type>/`.
+    ///
+    /// The id may be written with a leading `#`, as it is when one tag
+    /// references another.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use minecraft_assets::api::*;
+    /// # let assets = AssetPack::at_path("foo");
+    /// let tag = assets.load_tag("blocks", "logs");
+    /// let tag = assets.load_tag("items", "minecraft:planks");
+    /// let tag = assets.load_tag("blocks", "#minecraft:oak_logs");
+    /// ```
+    pub fn load_tag(&self, tag_type: &str, tag_id: &str) -> Result<Tag> {
+        let tag_id = tag_id.strip_prefix('#').unwrap_or(tag_id);
+
+        let id = match tag_id.split_once(':') {
+            Some((namespace, path)) => format!("{}:{}/{}", namespace, tag_type, path),
+            None => format!("{}/{}", tag_type, tag_id),
+        };
+
+        self.load_resource(&ResourceIdentifier::new_owned(ResourceKind::Tag, id))
+    }
+
+    /// Writes the given [`BlockStates`] to the file of the block with the
+    /// provided id, replacing the file if it exists.
+    ///
+    /// See [`save_resource()`][Self::save_resource].
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use minecraft_assets::api::*;
+    /// # let assets = AssetPack::at_path("foo");
+    /// let states = assets.load_blockstates("stone").unwrap();
+    /// assets.save_blockstates("mymod:marble", &states).unwrap();
+    /// ```
+    pub fn save_blockstates(&self, block_id: &str, states: &BlockStates) -> Result<()> {
+        self.save_resource(&ResourceIdentifier::blockstates(block_id), states)
+    }
+
+    /// Writes the given block [`Model`] to the file identified by the given
+    /// name or path, replacing the file if it exists.
+    ///
+    /// See [`save_resource()`][Self::save_resource].
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use minecraft_assets::api::*;
+    /// # let assets = AssetPack::at_path("foo");
+    /// let mut model = assets.load_block_model("stone").unwrap();
+    /// model.ambient_occlusion = Some(false);
+    /// assets.save_block_model("stone", &model).unwrap();
+    /// ```
+    pub fn save_block_model(&self, model: &str, value: &Model) -> Result<()> {
+        self.save_resource(&ResourceIdentifier::block_model(model), value)
+    }
+
+    /// Writes the given item [`Model`] to the file identified by the given
+    /// name or path, replacing the file if it exists.
+    ///
+    /// See [`save_resource()`][Self::save_resource].
+    pub fn save_item_model(&self, model: &str, value: &Model) -> Result<()> {
+        self.save_resource(&ResourceIdentifier::item_model(model), value)
+    }
+
+    /// Serializes the given value and writes it to the file of the resource
+    /// referenced by the given [`ResourceIdentifier`], replacing the file if
+    /// it exists.
+    ///
+    /// The file is written to the path computed by
+    /// [`ResourcePath::for_resource()`], and any missing parent directories
+    /// are created. The value is written as pretty-printed JSON indented by
+    /// two spaces, which matches the formatting of the vanilla files.
+    ///
+    /// If the resource is a model, it is removed from the cache enabled by
+    /// [`with_cache()`][Self::with_cache]. If the resource is a model or a
+    /// block states file, the cache enabled by
+    /// [`with_resolve_cache()`][Self::with_resolve_cache] is invalidated. The
+    /// cache enabled by [`with_enumeration_cache()`][Self::with_enumeration_cache]
+    /// is always invalidated.
+    ///
+    /// This is only supported for packs created with
+    /// [`at_path()`][Self::at_path]. Otherwise, an error of kind
+    /// [`Unsupported`][io::ErrorKind::Unsupported] is returned.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use minecraft_assets::api::*;
+    /// # let assets = AssetPack::at_path("foo");
+    /// let tag = assets.load_tag("blocks", "logs").unwrap();
+    ///
+    /// let id = ResourceIdentifier::tag("mymod:blocks/logs");
+    /// assets.save_resource(&id, &tag).unwrap();
+    /// ```
+    pub fn save_resource<T>(&self, resource: &ResourceIdentifier, value: &T) -> Result<()>
+    where
+        T: Serialize + ?Sized,
+    {
+        let root = self.root.as_ref().ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::Unsupported,
+                "resources can only be saved to packs on the file system",
+            )
+        })?;
+
+        let path = ResourcePath::for_resource(root, resource).into_inner();
+        let bytes = serde_json::to_vec_pretty(value).map_err(|source| Error::Serialize {
+            path: path.clone(),
+            source,
+        })?;
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&path, bytes)?;
+
+        if resource.is_model() {
+            if let Some(ref cache) = self.model_cache {
+                cache
+                    .lock()
+                    .unwrap()
+                    .remove(&resource.to_canonical().to_owned());
+            }
+        }
+        if resource.is_model() || resource.kind() == ResourceKind::BlockStates {
+            self.invalidate_resolve_cache();
+        }
+        self.invalidate_enumeration();
+
+        Ok(())
+    }
+
+    fn load_resource<T>(&self, resource: &ResourceIdentifier) -> Result<T>
+    where
+        T: DeserializeOwned,
+    {
+        let bytes = self.load_bytes(resource)?;
+        Self::parse_bytes(self.root.as_deref(), self.lenient, resource, &bytes)
+    }
+
+    /// Parses the contents of the given resource, reporting the path of its
+    /// file relative to `root` if parsing fails.
+    ///
+    /// This does not take `&self` so that it can be called from other threads.
+    fn parse_bytes<T>(
+        root: Option<&Path>,
+        lenient: bool,
+        resource: &ResourceIdentifier,
+        bytes: &[u8],
+    ) -> Result<T>
+    where
+        T: DeserializeOwned,
+    {
+        from_json_slice(bytes, lenient).map_err(|source| Error::Parse {
+            path: Self::resource_path(root, resource),
+            source,
+        })
+    }
+
+    fn resource_path(root: Option<&Path>, resource: &ResourceIdentifier) -> PathBuf {
+        let root = root.unwrap_or_else(|| Path::new(""));
+        ResourcePath::for_resource(root, resource).into_inner()
+    }
+
+    fn load_model_recursive_into(
+        &self,
+        id: &ResourceIdentifier,
+        buf: &mut Vec<Model>,
+    ) -> Result<()> {
+        buf.clear();
+
+        ModelResolver::for_each_parent(
+            id.clone(),
+            |model| buf.push(model),
+            |next_id| self.load_model(next_id),
+        )
+    }
+
+    fn check_model_kind(kind: ResourceKind) -> Result<()> {
+        if matches!(kind, ResourceKind::BlockModel | ResourceKind::ItemModel) {
+            Ok(())
+        } else {
+            Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("{:?} is not a kind of model", kind),
+            )
+            .into())
+        }
+    }
+
+    fn load_model(&self, id: &ResourceIdentifier) -> Result<Model> {
+        let cache = match self.model_cache {
+            Some(ref cache) => cache,
+            None => return self.load_resource(id),
+        };
+
+        let key = id.to_canonical().to_owned();
+        if let Some(model) = cache.lock().unwrap().get(&key) {
+            return Ok(model.clone());
+        }
+
+        // Don't hold the lock while reading from the provider.
+        let model: Model = self.load_resource(id)?;
+        cache.lock().unwrap().insert(key, model.clone());
+
+        Ok(model)
+    }
+
+    fn load_bytes(&self, resource: &ResourceIdentifier) -> Result<Vec<u8>> {
+        match self.provider.load_resource(resource) {
+            Ok(bytes) => Ok(bytes),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {
+                let location = Self::resource_path(self.root.as_deref(), resource);
+                Err(Error::NotFound {
+                    location: location.to_string_lossy().into_owned(),
+                })
+            }
+            Err(e) => Err(e.into()),
+        }
+    }
+}
+
+/// Async versions of the loading methods, for packs on the local file system.
+///
+/// Files are read with [`tokio::fs`], and parsed on tokio's blocking thread
+/// pool, so these methods must be called from within a tokio runtime.
+///
+/// These methods are only supported for packs created with
+/// [`at_path()`][AssetPack::at_path]. Otherwise, an error of kind
+/// [`Unsupported`][io::ErrorKind::Unsupported] is returned.
+///
+/// **Requires the `tokio` feature.**
+#[cfg(feature = "tokio")]
+impl AssetPack {
+    /// Like [`load_blockstates()`][Self::load_blockstates], but async.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use minecraft_assets::api::*;
+    /// # async fn foo() {
+    /// let assets = AssetPack::at_path("~/.minecraft/");
+    /// let states = assets.load_blockstates_async("stone").await.unwrap();
+    /// # }
+    /// ```
+    pub async fn load_blockstates_async(&self, block_id: &str) -> Result<BlockStates> {
+        let states: BlockStates = self
+            .load_resource_async(&ResourceIdentifier::blockstates(block_id))
+            .await?;
+
+        if self.strict {
+            states
+                .validate()
+                .map_err(|value| Error::InvalidRotation { value })?;
+        }
+
+        Ok(states)
+    }
+
+    /// Like [`load_block_model()`][Self::load_block_model], but async.
+    pub async fn load_block_model_async(&self, model: &str) -> Result<Model> {
+        self.load_resource_async(&ResourceIdentifier::block_model(model))
+            .await
+    }
+
+    /// Like [`load_item_model()`][Self::load_item_model], but async.
+    pub async fn load_item_model_async(&self, model: &str) -> Result<Model> {
+        self.load_resource_async(&ResourceIdentifier::item_model(model))
+            .await
+    }
+
+    /// Like [`load_block_model_recursive()`][Self::load_block_model_recursive],
+    /// but async.
+    ///
+    /// Each parent is loaded in turn, since its id is only known once its
+    /// child has been parsed.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use minecraft_assets::api::*;
+    /// # async fn foo() {
+    /// let assets = AssetPack::at_path("~/.minecraft/");
+    ///
+    /// let models = assets
+    ///     .load_block_model_recursive_async("stone")
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub async fn load_block_model_recursive_async(&self, model: &str) -> Result<Vec<Model>> {
+        self.load_model_recursive_async(&ResourceIdentifier::block_model(model))
+            .await
+    }
+
+    /// Like [`load_item_model_recursive()`][Self::load_item_model_recursive],
+    /// but async.
+    ///
+    /// Each parent is loaded in turn, since its id is only known once its
+    /// child has been parsed.
+    pub async fn load_item_model_recursive_async(&self, model: &str) -> Result<Vec<Model>> {
+        self.load_model_recursive_async(&ResourceIdentifier::item_model(model))
+            .await
+    }
+
+    /// Like [`load_model_recursive()`][Self::load_model_recursive], but async.
+    pub async fn load_model_recursive_async(
+        &self,
+        id: &ResourceIdentifier<'_>,
+    ) -> Result<Vec<Model>> {
+        Self::check_model_kind(id.kind())?;
+
+        let mut models = Vec::new();
+        let mut current = id.to_owned();
+
+        loop {
+            let model: Model = self.load_resource_async(&current).await?;
+            let parent = model
+                .parent
+                .as_deref()
+                .and_then(|parent| current.parent_location(parent));
+
+            models.push(model);
+
+            match parent {
+                Some(parent) => current = parent,
+                None => break,
+            }
+        }
+
+        Ok(models)
+    }
+
+    async fn load_resource_async<T>(&self, resource: &ResourceIdentifier<'_>) -> Result<T>
+    where
+        T: DeserializeOwned + Send + 'static,
+    {
+        let root = self.root.as_ref().ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::Unsupported,
+                "resources can only be loaded asynchronously from packs on the file system",
+            )
+        })?;
+        let path = ResourcePath::for_resource(root, resource);
+
+        let bytes = match tokio::fs::read(&path).await {
+            Ok(bytes) => bytes,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {
+                return Err(Error::NotFound {
+                    location: path.to_string_lossy().into_owned(),
+                })
+            }
+            Err(e) => return Err(e.into()),
+        };
+
+        let lenient = self.lenient;
+        let parsed = tokio::task::spawn_blocking(move || {
+            from_json_slice(&bytes, lenient).map_err(|source| Error::Parse {
+                path: path.into_inner(),
+                source,
+            })
+        })
+        .await
+        .map_err(io::Error::other)?;
+
+        parsed
+    }
+}
+
+/// The models returned by [`AssetPack::load_all_block_models()`].
+///
+/// **Requires the `rayon` feature.**
+#[cfg(feature = "rayon")]
+#[derive(Debug, Default)]
+pub struct LoadedModels {
+    /// The models that were loaded successfully, by id.
+    pub models: HashMap<ResourceIdentifier<'static>, Model>,
+
+    /// The ids of the models that could not be loaded, along with the reason
+    /// why.
+    pub errors: Vec<(ResourceIdentifier<'static>, Error)>,
+}
+
+/// An index of the resources in one namespace of a pack, as returned by
+/// [`AssetPack::export_manifest()`].
+///
+/// # Example
+///
+/// ```
+/// # use minecraft_assets::api::*;
+/// let json = r#"{
+///     "namespace": "minecraft",
+///     "resources": {
+///         "block_states": [{ "location": "minecraft:stone" }],
+///         "block_model": [{ "location": "minecraft:block/stone", "hash": 42 }]
+///     }
+/// }"#;
+///
+/// let manifest: Manifest = serde_json::from_str(json).unwrap();
+///
+/// assert_eq!(manifest.len(), 2);
+/// assert_eq!(manifest.resources[&ResourceKind::BlockModel][0].hash, Some(42));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Manifest {
+    /// The namespace that the resources belong to.
+    pub namespace: String,
+
+    /// The resources in the namespace, by kind.
+    pub resources: BTreeMap<ResourceKind, Vec<ManifestEntry>>,
+}
+
+impl Manifest {
+    /// Returns the total number of resources listed in the manifest.
+    pub fn len(&self) -> usize {
+        self.resources.values().map(Vec::len).sum()
+    }
+
+    /// Returns `true` if the manifest lists no resources.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// A single resource listed in a [`Manifest`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ManifestEntry {
+    /// The [canonical] id of the resource.
+    ///
+    /// [canonical]: ResourceIdentifier::to_canonical
+    pub location: String,
+
+    /// A hash of the contents of the resource, if one was computed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hash: Option<u64>,
+}
+
+/// A reference to a missing model, as returned by
+/// [`AssetPack::validate_blockstate_references()`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationIssue {
+    /// The [canonical] id of the block states that reference the model.
+    ///
+    /// [canonical]: ResourceIdentifier::to_canonical
+    pub blockstates: ResourceIdentifier<'static>,
+
+    /// The model reference as written in the block states file (e.g.,
+    /// `"block/stone"`).
+    pub model: String,
+}
+
+/// A texture whose dimensions do not match how it is animated, as returned by
+/// [`AssetPack::validate_texture_dimensions()`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextureIssue {
+    /// The [canonical] id of the texture.
+    ///
+    /// [canonical]: ResourceIdentifier::to_canonical
+    pub texture: ResourceIdentifier<'static>,
+
+    /// The width of the texture in pixels.
+    pub width: u32,
+
+    /// The height of the texture in pixels.
+    pub height: u32,
+
+    /// What is wrong with the dimensions of the texture.
+    pub kind: TextureIssueKind,
+}
+
+/// What is wrong with the dimensions of a texture in a [`TextureIssue`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TextureIssueKind {
+    /// The texture is in `textures/block/` and has no animation, but is not
+    /// square.
+    NotSquare,
+
+    /// The texture is animated, but is not a whole number of frames wide and
+    /// tall.
+    PartialFrames {
+        /// The width of each frame in pixels.
+        frame_width: u32,
+
+        /// The height of each frame in pixels.
+        frame_height: u32,
+    },
+}
+
+/// A fully resolved block model together with the rotation it should be
+/// rendered with, as returned by [`AssetPack::place_model()`].
+///
+/// The fields other than [`model`][Self::model] are copied from the
+/// [`ModelProperties`] in the block states file.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlacedModel {
+    /// The resolved model, with no parent.
+    pub model: Model,
+
+    /// Rotation of the model on the x-axis in increments of 90 degrees.
+    pub x: i32,
+
+    /// Rotation of the model on the y-axis in increments of 90 degrees.
+    pub y: i32,
+
+    /// Whether the textures of the model should stay fixed when the model is
+    /// rotated.
+    pub uv_lock: bool,
+}
+
+/// The models used by every state of a block, as returned by
+/// [`AssetPack::load_blockstates_resolved()`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum ResolvedBlockStates {
+    /// The models of each variant of a block defined with
+    /// [`BlockStates::Variants`], by variant name.
+    Variants(BTreeMap<String, Vec<PlacedModel>>),
+
+    /// The models of each case of a block defined with
+    /// [`BlockStates::Multipart`], in order.
+    Multipart(Vec<ResolvedCase>),
+}
+
+impl ResolvedBlockStates {
+    /// Returns the models of each variant, or `None` if the block is defined
+    /// with [`BlockStates::Multipart`].
+    pub fn variants(&self) -> Option<&BTreeMap<String, Vec<PlacedModel>>> {
+        match self {
+            Self::Variants(variants) => Some(variants),
+            Self::Multipart(_) => None,
+        }
+    }
+
+    /// Returns the models of each case, or `None` if the block is defined
+    /// with [`BlockStates::Variants`].
+    pub fn cases(&self) -> Option<&[ResolvedCase]> {
+        match self {
+            Self::Variants(_) => None,
+            Self::Multipart(cases) => Some(&cases[..]),
+        }
+    }
+}
+
+/// The models of a single case of a [`BlockStates::Multipart`] block, in a
+/// [`ResolvedBlockStates`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResolvedCase {
+    /// The condition under which the models apply, copied from the block
+    /// states. If `None`, the models always apply.
+    pub when: Option<WhenClause>,
+
+    /// The placed models.
+    pub models: Vec<PlacedModel>,
+}
+
+/// An entry in the chain of models returned by
+/// [`AssetPack::load_item_model_recursive_with_builtin()`].
+#[derive(Debug, Clone, PartialEq)]
+#[allow(clippy::large_enum_variant)]
+pub enum ModelChainEntry {
+    /// A model that was loaded from a file.
+    Model(Model),
+
+    /// A builtin model that has no corresponding file, identified by its name
+    /// without the `builtin/` prefix (e.g., `"generated"` or `"entity"`).
+    Builtin(String),
+}
+
+impl ModelChainEntry {
+    /// Returns the loaded [`Model`], or `None` if this is a builtin.
+    pub fn as_model(&self) -> Option<&Model> {
+        match self {
+            Self::Model(model) => Some(model),
+            Self::Builtin(_) => None,
+        }
+    }
+
+    /// Returns the name of the builtin model, or `None` if this is a loaded
+    /// model.
+    pub fn builtin_name(&self) -> Option<&str> {
+        match self {
+            Self::Model(_) => None,
+            Self::Builtin(name) => Some(name),
+        }
+    }
+}
+
+/// Returns `true` if `path` matches the glob `pattern`, as described in
+/// [`AssetPack::glob_resources()`].
+fn glob_match(pattern: &[u8], path: &[u8]) -> bool {
+    // Whether `pattern[p..]` matches `path[i..]` only depends on `p` and `i`,
+    // so each pair is only matched once, however many wildcards the pattern
+    // has.
+    let mut memo = vec![None; (pattern.len() + 1) * (path.len() + 1)];
+    glob_match_from(pattern, path, 0, 0, &mut memo)
+}
+
+fn glob_match_from(
+    pattern: &[u8],
+    path: &[u8],
+    p: usize,
+    i: usize,
+    memo: &mut [Option<bool>],
+) -> bool {
+    let key = p * (path.len() + 1) + i;
+    if let Some(matched) = memo[key] {
+        return matched;
+    }
+
+    let matched = match &pattern[p..] {
+        [] => i == path.len(),
+        [b'*', b'*', b'/', ..] => {
+            glob_match_from(pattern, path, p + 3, i, memo)
+                || (i..path.len())
+                    .any(|j| path[j] == b'/' && glob_match_from(pattern, path, p + 3, j + 1, memo))
+        }
+        [b'*', b'*', ..] => {
+            (i..=path.len()).any(|j| glob_match_from(pattern, path, p + 2, j, memo))
+        }
+        [b'*', ..] => {
+            let segment_end = path[i..]
+                .iter()
+                .position(|&c| c == b'/')
+                .map_or(path.len(), |len| i + len);
+            (i..=segment_end).any(|j| glob_match_from(pattern, path, p + 1, j, memo))
+        }
+        [c, ..] => path.get(i) == Some(c) && glob_match_from(pattern, path, p + 1, i + 1, memo),
+    };
+
+    memo[key] = Some(matched);
+    matched
+}
+
+/// Pushes the subdirectories of `directory` that are not (and do not contain)
+/// one of the `known` directories onto `unknown`, as described in
+/// [`AssetPack::enumerate_unknown()`].
+///
+/// `relative` is the path of `directory` relative to the namespace directory.
+fn find_unknown_directories(
+    directory: &Path,
+    relative: &str,
+    known: &[&str],
+    unknown: &mut Vec<(String, PathBuf)>,
+) -> io::Result<()> {
+    for entry in fs::read_dir(directory)? {
+        let entry = entry?;
+        if !entry.file_type()?.is_dir() {
+            continue;
+        }
+
+        let name = entry.file_name();
+        let name = if relative.is_empty() {
+            name.to_string_lossy().into_owned()
+        } else {
+            format!("{}/{}", relative, name.to_string_lossy())
+        };
+
+        if known.contains(&name.as_str()) {
+            continue;
+        }
+
+        let is_ancestor = known.iter().any(|known| {
+            known
+                .strip_prefix(name.as_str())
+                .is_some_and(|rest| rest.starts_with('/'))
+        });
+
+        if is_ancestor {
+            find_unknown_directories(&entry.path(), &name, known, unknown)?;
+        } else {
+            unknown.push((name, entry.path()));
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns the width and height of the PNG image with the given contents, or
+/// `None` if the contents do not start with a PNG header.
+fn png_dimensions(bytes: &[u8]) -> Option<(u32, u32)> {
+    const SIGNATURE: &[u8] = b"\x89PNG\r\n\x1a\n";
+
+    // The signature is followed by the `IHDR` chunk: its length, its type,
+    // and then the width and height as big-endian integers.
+    let header = bytes.strip_prefix(SIGNATURE)?.get(4..16)?;
+    if &header[..4] != b"IHDR" {
+        return None;
+    }
+
+    let width = u32::from_be_bytes(header[4..8].try_into().unwrap());
+    let height = u32::from_be_bytes(header[8..12].try_into().unwrap());
+    Some((width, height))
+}
+
+/// Hashes the given bytes with the 64-bit FNV-1a algorithm, which has no
+/// random seed.
+fn fnv1a_hash(bytes: &[u8]) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0100_0000_01b3;
+
+    bytes.iter().fold(OFFSET_BASIS, |hash, &byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(PRIME)
+    })
+}
+
+/// Deserializes a value from the given JSON, which may be [JSON5] if
+/// `lenient` is `true`.
+///
+/// [JSON5]: <https://json5.org/>
+fn from_json_slice<T>(bytes: &[u8], lenient: bool) -> serde_json::Result<T>
+where
+    T: DeserializeOwned,
+{
+    #[cfg(feature = "json5")]
+    if lenient {
+        use serde::de::Error as _;
+
+        // Going through a `Value` lets errors in the shape of the data be
+        // reported as they would be in strict mode.
+        let text = std::str::from_utf8(bytes).map_err(serde_json::Error::custom)?;
+        let value: serde_json::Value = json5::from_str(text).map_err(serde_json::Error::custom)?;
+        return serde_json::from_value(value);
+    }
+
+    #[cfg(not(feature = "json5"))]
+    let _ = lenient;
+
+    serde_json::from_slice(bytes)
 }
 
 impl Deref for AssetPack {
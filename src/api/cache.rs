@@ -0,0 +1,109 @@
+use std::{collections::HashMap, hash::Hash, sync::Mutex};
+
+/// A thread-safe, fixed-capacity cache that evicts the least-recently-used
+/// entry when full.
+///
+/// Eviction scans all entries, so this is intended for caches of modest size
+/// where the cached values are expensive to compute.
+pub(crate) struct LruCache<K, V> {
+    capacity: usize,
+    inner: Mutex<LruInner<K, V>>,
+}
+
+struct LruInner<K, V> {
+    entries: HashMap<K, (V, u64)>,
+    tick: u64,
+}
+
+impl<K, V> LruCache<K, V>
+where
+    K: Eq + Hash + Clone,
+    V: Clone,
+{
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            inner: Mutex::new(LruInner {
+                entries: HashMap::new(),
+                tick: 0,
+            }),
+        }
+    }
+
+    /// Returns a clone of the cached value for `key`, marking it as recently
+    /// used.
+    pub fn get(&self, key: &K) -> Option<V> {
+        let mut inner = self.inner.lock().unwrap();
+        inner.tick += 1;
+        let tick = inner.tick;
+
+        inner.entries.get_mut(key).map(|(value, last_used)| {
+            *last_used = tick;
+            value.clone()
+        })
+    }
+
+    /// Inserts a value into the cache, evicting the least-recently-used entry
+    /// if the cache is full.
+    pub fn insert(&self, key: K, value: V) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        let mut inner = self.inner.lock().unwrap();
+        inner.tick += 1;
+        let tick = inner.tick;
+
+        if !inner.entries.contains_key(&key) && inner.entries.len() >= self.capacity {
+            let oldest = inner
+                .entries
+                .iter()
+                .min_by_key(|(_, (_, last_used))| *last_used)
+                .map(|(key, _)| key.clone());
+
+            if let Some(oldest) = oldest {
+                inner.entries.remove(&oldest);
+            }
+        }
+
+        inner.entries.insert(key, (value, tick));
+    }
+
+    /// Removes all entries from the cache.
+    pub fn clear(&self) {
+        self.inner.lock().unwrap().entries.clear();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_evicts_least_recently_used() {
+        let cache = LruCache::new(2);
+
+        cache.insert("a", 1);
+        cache.insert("b", 2);
+
+        // Touch "a" so that "b" becomes the least-recently-used entry.
+        assert_eq!(cache.get(&"a"), Some(1));
+
+        cache.insert("c", 3);
+
+        assert_eq!(cache.get(&"a"), Some(1));
+        assert_eq!(cache.get(&"b"), None);
+        assert_eq!(cache.get(&"c"), Some(3));
+
+        cache.clear();
+        assert_eq!(cache.get(&"a"), None);
+    }
+
+    #[test]
+    fn test_zero_capacity() {
+        let cache = LruCache::new(0);
+
+        cache.insert("a", 1);
+        assert_eq!(cache.get(&"a"), None);
+    }
+}
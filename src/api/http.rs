@@ -0,0 +1,236 @@
+use std::io;
+
+use crate::api::{
+    EnumerateResources, LoadResource, ResourceIdentifier, ResourceKind, ResourcePath,
+};
+
+/// A [`ResourceProvider`] that loads individual resources over HTTP.
+///
+/// Each resource is fetched lazily with a `GET` request to
+/// `<base_url>/<path>`, where `<path>` is the resource's path relative to the
+/// root of the pack (e.g., `assets/minecraft/blockstates/stone.json`). This is
+/// useful for packs served from a web server or CDN, since there is no need to
+/// download the whole pack up front.
+///
+/// HTTP has no way to list the contents of a directory, so
+/// [`enumerate_resources()`] always returns an error of kind
+/// [`Unsupported`][io::ErrorKind::Unsupported].
+///
+/// **Requires the `http` feature.**
+///
+/// [`ResourceProvider`]: crate::api::ResourceProvider
+/// [`enumerate_resources()`]: EnumerateResources::enumerate_resources
+///
+/// # Example
+///
+/// ```no_run
+/// use minecraft_assets::api::*;
+///
+/// let provider = HttpResourceProvider::new("https://example.com/packs/my_pack");
+/// let assets = AssetPack::new(provider);
+///
+/// let states = assets.load_blockstates("stone").unwrap();
+/// ```
+pub struct HttpResourceProvider {
+    base_url: String,
+    client: reqwest::blocking::Client,
+}
+
+impl HttpResourceProvider {
+    /// Returns a new provider that loads resources relative to the given base
+    /// URL.
+    ///
+    /// The base URL should point to the directory that contains the `assets/`
+    /// and (optionally) `data/` directories.
+    pub fn new(base_url: impl Into<String>) -> Self {
+        Self::with_client(base_url, reqwest::blocking::Client::new())
+    }
+
+    /// Like [`new()`][Self::new], but issues requests using the given client,
+    /// e.g., to configure timeouts or default headers.
+    pub fn with_client(base_url: impl Into<String>, client: reqwest::blocking::Client) -> Self {
+        let mut base_url = base_url.into();
+        while base_url.ends_with('/') {
+            base_url.pop();
+        }
+
+        Self { base_url, client }
+    }
+
+    /// Returns the URL from which the given resource is loaded.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::api::*;
+    /// let provider = HttpResourceProvider::new("https://example.com/pack/");
+    /// let id = ResourceIdentifier::blockstates("stone");
+    ///
+    /// assert_eq!(
+    ///     provider.url_for(&id),
+    ///     "https://example.com/pack/assets/minecraft/blockstates/stone.json"
+    /// );
+    /// ```
+    pub fn url_for(&self, id: &ResourceIdentifier) -> String {
+        let path = ResourcePath::for_resource("", id);
+
+        let components: Vec<_> = path
+            .components()
+            .map(|component| component.as_os_str().to_string_lossy())
+            .collect();
+
+        format!("{}/{}", self.base_url, components.join("/"))
+    }
+}
+
+impl EnumerateResources for HttpResourceProvider {
+    fn enumerate_resources(
+        &self,
+        _namespace: &str,
+        _kind: ResourceKind,
+    ) -> Result<Vec<ResourceIdentifier<'static>>, io::Error> {
+        Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "resources cannot be enumerated over HTTP",
+        ))
+    }
+}
+
+impl LoadResource for HttpResourceProvider {
+    fn load_resource(&self, id: &ResourceIdentifier) -> Result<Vec<u8>, io::Error> {
+        let url = self.url_for(id);
+
+        let response = self.client.get(&url).send().map_err(io::Error::other)?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let kind = match status {
+                reqwest::StatusCode::NOT_FOUND | reqwest::StatusCode::GONE => {
+                    io::ErrorKind::NotFound
+                }
+                reqwest::StatusCode::UNAUTHORIZED | reqwest::StatusCode::FORBIDDEN => {
+                    io::ErrorKind::PermissionDenied
+                }
+                _ => io::ErrorKind::Other,
+            };
+
+            return Err(io::Error::new(
+                kind,
+                format!("GET {} returned {}", url, status),
+            ));
+        }
+
+        let bytes = response.bytes().map_err(io::Error::other)?;
+
+        Ok(bytes.to_vec())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use std::{
+        io::{BufRead, BufReader, Write},
+        net::TcpListener,
+        thread,
+    };
+
+    /// Serves `responses.len()` requests on a local port, replying to each
+    /// path with the matching status and body. Returns the base URL.
+    fn serve(responses: Vec<(&'static str, u16, &'static str)>) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        thread::spawn(move || {
+            for _ in 0..responses.len() {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+                let mut request_line = String::new();
+                reader.read_line(&mut request_line).unwrap();
+                let path = request_line.split(' ').nth(1).unwrap().to_string();
+
+                // Drain the headers.
+                let mut line = String::new();
+                while reader.read_line(&mut line).unwrap() > 2 {
+                    line.clear();
+                }
+
+                let (status, body) = responses
+                    .iter()
+                    .find(|(p, _, _)| *p == path)
+                    .map(|(_, status, body)| (*status, *body))
+                    .unwrap_or((404, ""));
+
+                write!(
+                    stream,
+                    "HTTP/1.1 {} X\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                )
+                .unwrap();
+            }
+        });
+
+        format!("http://{}/pack", addr)
+    }
+
+    #[test]
+    fn test_load_resource() {
+        let base_url = serve(vec![(
+            "/pack/assets/minecraft/blockstates/stone.json",
+            200,
+            r#"{"variants":{}}"#,
+        )]);
+        let provider = HttpResourceProvider::new(base_url);
+
+        let bytes = provider
+            .load_resource(&ResourceIdentifier::blockstates("stone"))
+            .unwrap();
+        assert_eq!(bytes, br#"{"variants":{}}"#);
+    }
+
+    #[test]
+    fn test_non_success_status() {
+        let base_url = serve(vec![
+            ("/pack/assets/minecraft/blockstates/stone.json", 404, ""),
+            ("/pack/assets/minecraft/blockstates/dirt.json", 500, "oops"),
+        ]);
+        let provider = HttpResourceProvider::new(base_url);
+
+        let err = provider
+            .load_resource(&ResourceIdentifier::blockstates("stone"))
+            .unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::NotFound);
+
+        let err = provider
+            .load_resource(&ResourceIdentifier::blockstates("dirt"))
+            .unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::Other);
+    }
+
+    #[test]
+    fn test_enumerate_unsupported() {
+        let provider = HttpResourceProvider::new("http://localhost");
+
+        let err = provider
+            .enumerate_resources("minecraft", ResourceKind::BlockStates)
+            .unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::Unsupported);
+    }
+
+    #[test]
+    fn test_url_for_texture_meta() {
+        let provider = HttpResourceProvider::new("http://localhost");
+        let id = ResourceIdentifier::new(ResourceKind::TextureMeta, "block/kelp");
+
+        let url = provider.url_for(&id);
+        assert_eq!(
+            url,
+            "http://localhost/assets/minecraft/textures/block/kelp.png.mcmeta"
+        );
+        assert!(url.ends_with(&id.file_name()));
+    }
+}
@@ -10,38 +10,102 @@
 //!
 //! Resources can be enumerated and loaded using the [`ResourceProvider`] trait.
 //! This crate provides the [`FileSystemResourceProvider`] as a convenient
-//! implementation of this trait.
+//! implementation of this trait. With the `http` feature enabled, the
+//! `HttpResourceProvider` can load resources from a web server. With the `zip`
+//! feature enabled, the `ZipResourceProvider` can load resources from a zipped
+//! pack, and the `JarResourceProvider` can load the vanilla resources from a
+//! Minecraft client jar.
 //!
 //! ## Asset Pack
 //!
-//! Resources can be ergonomically loaded through the [`AssetPack`] API.
+//! Resources can be ergonomically loaded through the [`AssetPack`] API. With
+//! the `tokio` feature enabled, async versions of its loading methods are
+//! available.
 
-use std::io;
+use std::{io, path::PathBuf};
 
+#[cfg(feature = "zip")]
+mod archive;
 mod asset_pack;
+mod cache;
+#[cfg(feature = "http")]
+mod http;
 mod provider;
 mod resolve;
 mod resource;
 
-pub use asset_pack::AssetPack;
+#[cfg(feature = "zip")]
+pub use archive::{JarResourceProvider, ZipResourceProvider};
+#[cfg(feature = "rayon")]
+pub use asset_pack::LoadedModels;
+pub use asset_pack::{
+    AssetPack, Manifest, ManifestEntry, ModelChainEntry, PlacedModel, ResolvedBlockStates,
+    ResolvedCase, TextureIssue, TextureIssueKind, ValidationIssue,
+};
+#[cfg(feature = "http")]
+pub use http::HttpResourceProvider;
 pub use provider::{
     EnumerateResources, FileSystemResourceProvider, LoadResource, ResourceProvider,
 };
-pub use resolve::ModelResolver;
+pub use resolve::{ModelResolver, ResolvedModel};
 pub use resource::{
-    ModelIdentifier, ResourceCategory, ResourceIdentifier, ResourceKind, ResourcePath,
-    MINECRAFT_NAMESPACE,
+    Builtin, InternedIdentifier, ModelIdentifier, ResourceCategory, ResourceIdentifier,
+    ResourceInterner, ResourceKind, ResourcePath, MINECRAFT_NAMESPACE,
 };
 
 /// Error types that can be returned from API methods.
 #[derive(Debug, thiserror::Error)]
 #[allow(missing_docs)]
 pub enum Error {
+    /// The requested resource does not exist.
+    ///
+    /// `location` is the path where the resource was looked for. It is
+    /// relative to the root of the pack, unless the pack was opened with
+    /// [`AssetPack::at_path()`].
+    #[error("resource not found: {location}")]
+    NotFound { location: String },
+
+    /// A model in a block states file has an `x` or `y` rotation that is not
+    /// a multiple of 90 degrees between 0 and 270.
+    ///
+    /// This is only returned by an [`AssetPack`] in
+    /// [strict mode][AssetPack::strict].
+    #[error("invalid model rotation: {value}")]
+    InvalidRotation { value: i32 },
+
     #[error(transparent)]
     IoError(#[from] io::Error),
 
+    /// A resource could not be parsed.
+    ///
+    /// `path` is the path of the file that failed to parse, which is relative
+    /// to the root of the pack unless the pack was opened with
+    /// [`AssetPack::at_path()`]. The line and column of the failure are
+    /// included in `source`.
+    #[error("failed to parse {}: {source}", path.display())]
+    Parse {
+        path: PathBuf,
+        #[source]
+        source: serde_json::Error,
+    },
+
+    /// A resource could not be serialized to be saved.
+    ///
+    /// `path` is the path of the file that the resource was going to be
+    /// written to. Nothing is written to the file.
+    #[error("failed to serialize {}: {source}", path.display())]
+    Serialize {
+        path: PathBuf,
+        #[source]
+        source: serde_json::Error,
+    },
+
+    /// No longer returned. Resources that fail to parse are reported as
+    /// [`Parse`][Error::Parse] errors, which include the path of the file,
+    /// and resources that fail to serialize as
+    /// [`Serialize`][Error::Serialize] errors.
     #[error(transparent)]
-    ParseError(#[from] serde_json::Error),
+    ParseError(serde_json::Error),
 }
 
 /// Result alias for convenience.
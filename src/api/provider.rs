@@ -3,7 +3,7 @@ use std::{
     path::{Path, PathBuf},
 };
 
-use crate::api::{ResourceIdentifier, ResourceKind, ResourcePath};
+use crate::api::{ResourceCategory, ResourceIdentifier, ResourceKind, ResourcePath};
 
 /*
  dMMMMMMP dMMMMb  .aMMMb  dMP dMMMMMMP .dMMMb
@@ -22,6 +22,23 @@ pub trait EnumerateResources {
         namespace: &str,
         kind: ResourceKind,
     ) -> Result<Vec<ResourceIdentifier<'static>>, io::Error>;
+
+    /// Enumerates the namespaces that contain resources of the given
+    /// [`ResourceCategory`], in sorted order.
+    ///
+    /// Hidden directories and directories whose names start with `_` are not
+    /// namespaces, and should be skipped, in the same way that resource files
+    /// whose names start with `_` are skipped.
+    ///
+    /// The default implementation returns an error of kind
+    /// [`Unsupported`][io::ErrorKind::Unsupported].
+    fn enumerate_namespaces(&self, category: ResourceCategory) -> Result<Vec<String>, io::Error> {
+        let _ = category;
+        Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "namespaces cannot be enumerated by this provider",
+        ))
+    }
 }
 
 /// Indicates that a type can load provide the raw data of resources.
@@ -29,12 +46,24 @@ pub trait LoadResource {
     /// Returns the raw bytes of the resource referenced by the given
     /// [`ResourceIdentifier`].
     fn load_resource(&self, id: &ResourceIdentifier) -> Result<Vec<u8>, io::Error>;
+
+    /// Returns `true` if the resource referenced by the given
+    /// [`ResourceIdentifier`] exists.
+    ///
+    /// The default implementation tries to load the resource, so providers
+    /// that can check for a resource more cheaply should override it.
+    fn contains(&self, id: &ResourceIdentifier) -> bool {
+        self.load_resource(id).is_ok()
+    }
 }
 
 /// Marker trait for types that are [`EnumerateResources`] and [`LoadResource`].
-pub trait ResourceProvider: EnumerateResources + LoadResource {}
+///
+/// Providers must be [`Send`] and [`Sync`], so that an
+/// [`AssetPack`][crate::api::AssetPack] can be shared between threads.
+pub trait ResourceProvider: EnumerateResources + LoadResource + Send + Sync {}
 
-impl<T: EnumerateResources + LoadResource> ResourceProvider for T {}
+impl<T: EnumerateResources + LoadResource + Send + Sync> ResourceProvider for T {}
 
 /*
     dMMMMMP dMP dMP     dMMMMMP        .dMMMb  dMP dMP .dMMMb dMMMMMMP dMMMMMP dMMMMMMMMb
@@ -77,6 +106,21 @@ impl EnumerateResources for FileSystemResourceProvider {
         let directory = ResourcePath::for_kind(&self.root, namespace, kind);
         Ok(ResourceIter::new(directory, kind)?.collect())
     }
+
+    fn enumerate_namespaces(&self, category: ResourceCategory) -> Result<Vec<String>, io::Error> {
+        let mut namespaces = Vec::new();
+
+        for entry in fs::read_dir(self.root.join(category.directory()))? {
+            let entry = entry?;
+            let name = entry.file_name().to_string_lossy().into_owned();
+            if entry.file_type()?.is_dir() && !name.starts_with(['.', '_']) {
+                namespaces.push(name);
+            }
+        }
+
+        namespaces.sort();
+        Ok(namespaces)
+    }
 }
 
 impl LoadResource for FileSystemResourceProvider {
@@ -84,6 +128,10 @@ impl LoadResource for FileSystemResourceProvider {
         let path = ResourcePath::for_resource(&self.root, id);
         fs::read(path)
     }
+
+    fn contains(&self, id: &ResourceIdentifier) -> bool {
+        ResourcePath::for_resource(&self.root, id).is_file()
+    }
 }
 
 /*
@@ -150,21 +198,16 @@ impl ResourceIter {
                                 })
                         } else {
                             // Get file name and skip over UTF-8 errors.
-                            dir_entry.file_name().to_str().and_then(|file_name| {
-                                (
-                                    // Skip over files starting with '_'.
-                                    !file_name.starts_with('_') &&
-                                    // Skip over resources of the wrong kind (check the extension).
-                                    file_name.ends_with(self.kind.extension())
-                                )
-                                .then(|| {
-                                    // Cut the extension off the file name to
-                                    // get the resource name.
-                                    let dot_index =
-                                        file_name.len() - self.kind.extension().len() - 1;
-
-                                    let file_name = &file_name[..dot_index];
-
+                            dir_entry
+                                .file_name()
+                                .to_str()
+                                // Skip over files starting with '_'.
+                                .filter(|file_name| !file_name.starts_with('_'))
+                                // Skip over resources of the wrong kind (check
+                                // the extension), and cut the extension off
+                                // the file name to get the resource name.
+                                .and_then(|file_name| self.kind.strip_extension(file_name))
+                                .map(|file_name| {
                                     // Prepend any subdirectory paths
                                     let mut components = self.dir_names.clone();
                                     components.push(file_name.to_string());
@@ -175,7 +218,6 @@ impl ResourceIter {
                                         ResourceIdentifier::new_owned(self.kind, resource_path);
                                     DirOrResource::Resource(id)
                                 })
-                            })
                         }
                     })
             })
@@ -1,4 +1,7 @@
-use crate::schemas::models::{Display, Element, GuiLightMode, Model, Texture, Textures};
+use crate::{
+    api::ResourceIdentifier,
+    schemas::models::{Display, Element, GuiLightMode, Model, Texture, Textures},
+};
 
 /// Methods for resolving the properties of a [`Model`] with respect to its
 /// parents.
@@ -107,7 +110,8 @@ impl ModelResolver {
         let display = Self::resolve_display(models.clone());
         let ambient_occlusion = Self::resolve_ambient_occlusion(models.clone());
         let gui_light_mode = Self::resolve_gui_light_mode(models.clone());
-        let overrides = models.into_iter().next().unwrap().overrides.clone();
+        let render_type = Self::resolve_render_type(models.clone());
+        let child = models.into_iter().next().unwrap();
 
         Model {
             parent: None,
@@ -116,10 +120,75 @@ impl ModelResolver {
             elements,
             ambient_occlusion,
             gui_light_mode,
-            overrides,
+            render_type,
+            overrides: child.overrides.clone(),
+            extra: child.extra.clone(),
         }
     }
 
+    /// Loads the model with the given id along with all of its parents, and
+    /// resolves the model's [texture variables], in a single pass over the
+    /// chain of parents.
+    ///
+    /// `load_model` is called once for each model in the chain, starting with
+    /// `model`. Builtin parents (e.g., `builtin/generated`) end the chain. The
+    /// first error returned by `load_model` is returned.
+    ///
+    /// This is the same as calling
+    /// [`AssetPack::load_model_recursive()`] followed by
+    /// [`resolve_textures()`][Self::resolve_textures], but works with any
+    /// source of models.
+    ///
+    /// [texture variables]: Textures#texture-variables
+    /// [`AssetPack::load_model_recursive()`]: crate::api::AssetPack::load_model_recursive
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::api::*;
+    /// use std::collections::HashMap;
+    ///
+    /// use maplit::hashmap;
+    ///
+    /// use minecraft_assets::schemas::models::{Model, Textures};
+    ///
+    /// let mut models = HashMap::new();
+    /// models.insert(
+    ///     ResourceIdentifier::block_model("stone"),
+    ///     Model {
+    ///         parent: Some(String::from("block/cube_all")),
+    ///         textures: Some(Textures::from(hashmap! { "all" => "block/stone" })),
+    ///         ..Default::default()
+    ///     },
+    /// );
+    /// models.insert(
+    ///     ResourceIdentifier::block_model("cube_all"),
+    ///     Model {
+    ///         textures: Some(Textures::from(hashmap! { "particle" => "#all" })),
+    ///         ..Default::default()
+    ///     },
+    /// );
+    ///
+    /// let resolved = ModelResolver::resolve(&ResourceIdentifier::block_model("stone"), |id| {
+    ///     models.get(id).cloned().ok_or_else(|| id.to_owned())
+    /// })
+    /// .unwrap();
+    ///
+    /// assert_eq!(resolved.chain.len(), 2);
+    /// assert_eq!(resolved.textures.get("particle"), Some("block/stone"));
+    /// ```
+    pub fn resolve<L, E>(model: &ResourceIdentifier, load_model: L) -> Result<ResolvedModel, E>
+    where
+        L: FnMut(&ResourceIdentifier) -> Result<Model, E>,
+    {
+        let mut chain = Vec::new();
+        Self::for_each_parent(model.clone(), |model| chain.push(model), load_model)?;
+
+        let textures = Self::resolve_textures(chain.iter());
+
+        Ok(ResolvedModel { chain, textures })
+    }
+
     /// Iterates through a [`Model`] and all of its parents to resolve all of
     /// the model's [texture variables].
     ///
@@ -127,6 +196,17 @@ impl ModelResolver {
     /// the parent-child chain, and then substituting texture variables with
     /// concrete values where possible.
     ///
+    /// The models are merged one at a time, from the child up to the topmost
+    /// parent. When a model is merged, the variables it references are
+    /// substituted with the definitions of its descendants, and vice versa.
+    /// A variable defined by a descendant takes precedence over one of the
+    /// same name defined by an ancestor. Once every model has been merged,
+    /// references that remain (e.g., a reference to a variable that is itself
+    /// a reference, like `"particle": "#side"` and `"side": "#all"`) are
+    /// resolved against the merged map until no more can be resolved. Any
+    /// reference left at that point refers to a variable that is not defined
+    /// anywhere in the chain, or that is part of a cycle.
+    ///
     /// [texture variables]: Textures#texture-variables
     ///
     /// # Example
@@ -167,7 +247,62 @@ impl ModelResolver {
     /// assert_eq!(resolved, expected);
     /// ```
     pub fn resolve_textures<'a>(models: impl IntoIterator<Item = &'a Model>) -> Textures {
-        let mut textures = Textures::default();
+        Self::resolve_textures_with_base(models, Textures::default())
+    }
+
+    /// Like [`resolve_textures()`][Self::resolve_textures], but starts from
+    /// the given `base` textures before walking the chain of models.
+    ///
+    /// The variables in `base` take precedence over those defined by any of
+    /// the models, as if they were defined by a child of the first model. This
+    /// can be used to supply texture variables that are referenced by a model
+    /// but not defined by it or any of its parents.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::api::{ModelResolver};
+    /// use maplit::hashmap;
+    ///
+    /// use minecraft_assets::schemas::models::*;
+    ///
+    /// let parent = Model {
+    ///     textures: Some(Textures::from(hashmap! {
+    ///         "particle" => "#side",
+    ///     })),
+    ///     elements: Some(vec![Element {
+    ///         faces: hashmap! {
+    ///             BlockFace::North => ElementFace {
+    ///                 texture: Texture::from("#side"),
+    ///                 ..Default::default()
+    ///             },
+    ///         },
+    ///         ..Default::default()
+    ///     }]),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let child = Model {
+    ///     parent: Some(String::from("block/parent")),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let base = Textures::from(hashmap! {
+    ///     "side" => "block/stone",
+    /// });
+    ///
+    /// let textures = ModelResolver::resolve_textures_with_base([&child, &parent], base);
+    /// assert_eq!(textures.get("particle"), Some("block/stone"));
+    ///
+    /// let mut elements = ModelResolver::resolve_elements([&child, &parent]).unwrap();
+    /// ModelResolver::resolve_element_textures(&mut elements, &textures);
+    /// assert_eq!(elements[0].faces[&BlockFace::North].texture.0, "block/stone");
+    /// ```
+    pub fn resolve_textures_with_base<'a>(
+        models: impl IntoIterator<Item = &'a Model>,
+        base: Textures,
+    ) -> Textures {
+        let mut textures = base;
 
         for model in models.into_iter() {
             if let Some(mut parent_textures) = model.textures.clone() {
@@ -183,9 +318,70 @@ impl ModelResolver {
             }
         }
 
+        // Resolve chains of references that could only be followed once the
+        // whole map was merged. Each pass follows every chain one step
+        // further, so this terminates even if the references form a cycle.
+        for _ in 0..textures.len() {
+            let merged = textures.clone();
+            textures.resolve(&merged);
+
+            if textures == merged {
+                break;
+            }
+        }
+
         textures
     }
 
+    /// Like [`resolve_textures()`][Self::resolve_textures], but returns an
+    /// error listing the texture variable references that could not be
+    /// resolved (see [`Textures::unresolved()`]), if there are any.
+    ///
+    /// This can be used to validate that a model and its parents define every
+    /// texture variable that they reference.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::api::{ModelResolver};
+    /// use maplit::hashmap;
+    ///
+    /// use minecraft_assets::schemas::models::{Model, Textures};
+    ///
+    /// let child = Model {
+    ///     textures: Some(Textures::from(hashmap! {
+    ///         "side" => "block/stone",
+    ///     })),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let parent = Model {
+    ///     textures: Some(Textures::from(hashmap! {
+    ///         "particle" => "#side",
+    ///         "top" => "#missing",
+    ///     })),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let unresolved = ModelResolver::resolve_textures_checked([&child, &parent]);
+    /// assert_eq!(unresolved, Err(vec![String::from("#missing")]));
+    ///
+    /// let resolved = ModelResolver::resolve_textures_checked([&child]);
+    /// assert!(resolved.is_ok());
+    /// ```
+    pub fn resolve_textures_checked<'a>(
+        models: impl IntoIterator<Item = &'a Model>,
+    ) -> Result<Textures, Vec<String>> {
+        let textures = Self::resolve_textures(models);
+        let unresolved = textures.unresolved();
+
+        if unresolved.is_empty() {
+            Ok(textures)
+        } else {
+            Err(unresolved.into_iter().map(String::from).collect())
+        }
+    }
+
     /// Iterates through a [`Model`] and all of its parents to resolve the
     /// model's cuboid [`Element`]s.
     ///
@@ -298,6 +494,48 @@ impl ModelResolver {
         Self::first_model_where_some(models, |model| model.gui_light_mode.as_ref()).copied()
     }
 
+    /// Iterates through a [`Model`] and all of its parents to resolve the
+    /// model's render type.
+    ///
+    /// Similar to [`elements`] works by taking the first property value present
+    /// in the chain of parents.
+    ///
+    /// [`elements`]: Self::resolve_elements
+    pub fn resolve_render_type<'a>(models: impl IntoIterator<Item = &'a Model>) -> Option<String> {
+        Self::first_model_where_some(models, |model| model.render_type.as_ref()).cloned()
+    }
+
+    /// Calls `op` with the model that `current` refers to and each of its
+    /// ancestors in turn, loading each model with `load_model`. Builtin
+    /// parents end the chain.
+    pub(crate) fn for_each_parent<F, L, E>(
+        mut current: ResourceIdentifier,
+        mut op: F,
+        mut load_model: L,
+    ) -> Result<(), E>
+    where
+        F: FnMut(Model),
+        L: FnMut(&ResourceIdentifier) -> Result<Model, E>,
+    {
+        loop {
+            let model = load_model(&current)?;
+
+            let parent = model
+                .parent
+                .as_deref()
+                .and_then(|parent| current.parent_location(parent));
+
+            op(model);
+
+            match parent {
+                Some(parent) => current = parent,
+                None => break,
+            }
+        }
+
+        Ok(())
+    }
+
     fn first_model_where_some<'a, F, T>(
         models: impl IntoIterator<Item = &'a Model>,
         mut op: F,
@@ -314,3 +552,19 @@ impl ModelResolver {
         None
     }
 }
+
+/// A model along with its parents and its resolved textures, as returned by
+/// [`ModelResolver::resolve()`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResolvedModel {
+    /// The model followed by each of its parents, in increasing level of
+    /// parenthood.
+    ///
+    /// This can be passed to any of the other methods of [`ModelResolver`],
+    /// e.g., [`resolve_elements()`][ModelResolver::resolve_elements].
+    pub chain: Vec<Model>,
+
+    /// The model's textures, with texture variables resolved against its
+    /// parents.
+    pub textures: Textures,
+}
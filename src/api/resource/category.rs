@@ -1,5 +1,5 @@
 /// The category of a resource.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum ResourceCategory {
     /// Resources located in the `assets/` directory.
     Assets,
@@ -1,4 +1,4 @@
-use std::{borrow::Cow, fmt, hash::Hash};
+use std::{borrow::Cow, cmp::Ordering, fmt, hash::Hash, path::Path};
 
 #[allow(missing_docs)]
 pub const MINECRAFT_NAMESPACE: &str = "minecraft";
@@ -49,6 +49,46 @@ use crate::api::{ModelIdentifier, ResourceKind};
 /// resource packs prior to 1.13. See the [`ModelIdentifier`] docs for more
 /// information.
 ///
+/// # Equality and Ordering
+///
+/// Two identifiers are equal if they have the same [`kind()`][Self::kind],
+/// the same [`namespace()`][Self::namespace], and the same
+/// [`path()`][Self::path]. So `"stone"` and `"minecraft:stone"` are equal,
+/// and so are the block models `"stone"` and `"block/stone"`.
+///
+/// Identifiers are ordered by comparing those same three parts, in that
+/// order. Kinds are ordered as they are declared in [`ResourceKind`], and the
+/// namespace and path are compared as strings. This ordering is consistent
+/// with equality, so identifiers can be kept in sorted collections like
+/// [`BTreeSet`][std::collections::BTreeSet].
+///
+/// ```
+/// # use minecraft_assets::api::*;
+/// use std::collections::BTreeSet;
+///
+/// let ids: BTreeSet<_> = [
+///     ResourceIdentifier::texture("block/stone"),
+///     ResourceIdentifier::blockstates("zoo:stone"),
+///     ResourceIdentifier::blockstates("minecraft:stone"),
+///     ResourceIdentifier::blockstates("dirt"),
+///     ResourceIdentifier::blockstates("stone"),
+/// ]
+/// .into_iter()
+/// .collect();
+///
+/// // `"stone"` and `"minecraft:stone"` are the same block states.
+/// let ids: Vec<(ResourceKind, String)> = ids.iter().map(|id| (id.kind(), id.to_string())).collect();
+/// assert_eq!(
+///     ids,
+///     [
+///         (ResourceKind::BlockStates, String::from("minecraft:dirt")),
+///         (ResourceKind::BlockStates, String::from("minecraft:stone")),
+///         (ResourceKind::BlockStates, String::from("zoo:stone")),
+///         (ResourceKind::Texture, String::from("minecraft:block/stone")),
+///     ]
+/// );
+/// ```
+///
 /// [wiki]: <https://minecraft.fandom.com/wiki/Resource_location>
 /// [`ResourceIdentifiers`]: ResourceIdentifier
 #[derive(Clone)]
@@ -91,6 +131,79 @@ impl<'a> ResourceIdentifier<'a> {
         }
     }
 
+    /// Returns the [`ResourceIdentifier`] of the resource stored at the given
+    /// file path, or `None` if the path does not point to a resource inside
+    /// `root`.
+    ///
+    /// This is the inverse of [`ResourcePath::for_resource()`]. The `root`
+    /// argument should be the path to the directory containing the `assets/`
+    /// and (optionally) `data/` directories.
+    ///
+    /// The returned id always includes its namespace, and model ids include
+    /// their `block/` or `item/` prefix.
+    ///
+    /// [`ResourcePath::for_resource()`]: crate::api::ResourcePath::for_resource
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::api::*;
+    /// let id = ResourceIdentifier::from_path(
+    ///     "~/.minecraft",
+    ///     "~/.minecraft/assets/minecraft/blockstates/stone.json",
+    /// )
+    /// .unwrap();
+    /// assert_eq!(id.kind(), ResourceKind::BlockStates);
+    /// assert_eq!(id.as_str(), "minecraft:stone");
+    ///
+    /// let id = ResourceIdentifier::from_path(
+    ///     "~/.minecraft",
+    ///     "~/.minecraft/assets/foo/textures/block/bar.png.mcmeta",
+    /// )
+    /// .unwrap();
+    /// assert_eq!(id.kind(), ResourceKind::TextureMeta);
+    /// assert_eq!(id.as_str(), "foo:block/bar");
+    ///
+    /// let id = ResourceIdentifier::from_path("~/.minecraft", "~/Desktop/stone.json");
+    /// assert!(id.is_none());
+    /// ```
+    pub fn from_path(
+        root: impl AsRef<Path>,
+        path: impl AsRef<Path>,
+    ) -> Option<ResourceIdentifier<'static>> {
+        let relative = path.as_ref().strip_prefix(root).ok()?;
+
+        let components = relative
+            .components()
+            .map(|component| component.as_os_str().to_str())
+            .collect::<Option<Vec<_>>>()?;
+
+        // `<category>/<namespace>/<rest>`.
+        let (category, namespace, rest) = match &components[..] {
+            [category, namespace, rest @ ..] if !rest.is_empty() => {
+                (*category, *namespace, rest.join("/"))
+            }
+            _ => return None,
+        };
+
+        ResourceKind::ALL.iter().find_map(|&kind| {
+            if kind.category().directory() != category {
+                return None;
+            }
+
+            let path =
+                kind.strip_extension(rest.strip_prefix(kind.directory())?.strip_prefix('/')?)?;
+
+            let id = match kind {
+                ResourceKind::BlockModel => format!("{}:block/{}", namespace, path),
+                ResourceKind::ItemModel => format!("{}:item/{}", namespace, path),
+                _ => format!("{}:{}", namespace, path),
+            };
+
+            Some(ResourceIdentifier::new_owned(kind, id))
+        })
+    }
+
     /// Constructs a new [`ResourceIdentifier`] referencing the [`BlockStates`] of
     /// the given block id.
     ///
@@ -138,6 +251,40 @@ impl<'a> ResourceIdentifier<'a> {
         Self::new(ResourceKind::Texture, path)
     }
 
+    /// Constructs a new [`ResourceIdentifier`] referencing the [`Recipe`] with
+    /// the given id.
+    ///
+    /// [`Recipe`]: ResourceKind::Recipe
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::api::*;
+    /// let id = ResourceIdentifier::recipe("oak_planks");
+    /// let id = ResourceIdentifier::recipe("minecraft:iron_ingot_from_smelting_iron_ore");
+    /// ```
+    pub fn recipe(id: &'a str) -> Self {
+        Self::new(ResourceKind::Recipe, id)
+    }
+
+    /// Constructs a new [`ResourceIdentifier`] referencing the [`Tag`] with
+    /// the given id.
+    ///
+    /// The path of the id must begin with the type of the tag.
+    ///
+    /// [`Tag`]: ResourceKind::Tag
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::api::*;
+    /// let id = ResourceIdentifier::tag("blocks/logs");
+    /// let id = ResourceIdentifier::tag("minecraft:items/planks");
+    /// ```
+    pub fn tag(id: &'a str) -> Self {
+        Self::new(ResourceKind::Tag, id)
+    }
+
     /// Returns the underlying identifier as a string slice.
     ///
     /// # Example
@@ -217,6 +364,75 @@ impl<'a> ResourceIdentifier<'a> {
             .unwrap_or_else(|| &self.id)
     }
 
+    /// Returns a copy of this id with its [path][Self::path] replaced by the
+    /// result of `f`, keeping the namespace (if any) as it is written.
+    ///
+    /// For models, only the name after the `block/` or `item/` prefix is
+    /// passed to `f`, and the prefix is kept.
+    ///
+    /// If `f` returns [`Cow::Borrowed`] with the same contents as its input,
+    /// the id is returned unchanged, which does not allocate if `self` borrows
+    /// its string. Use [`to_owned()`][Self::to_owned] on the result to obtain
+    /// an id with a `'static` lifetime.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::api::*;
+    /// use std::borrow::Cow;
+    ///
+    /// let id = ResourceIdentifier::texture("foo:block/stone");
+    /// let upper = id.map_path(|path| Cow::Owned(path.to_uppercase()));
+    /// assert_eq!(upper.as_str(), "foo:BLOCK/STONE");
+    ///
+    /// let id = ResourceIdentifier::block_model("foo:block/stone");
+    /// let upper = id.map_path(|path| Cow::Owned(path.to_uppercase()));
+    /// assert_eq!(upper.as_str(), "foo:block/STONE");
+    ///
+    /// // Unchanged paths keep borrowing the original string.
+    /// let same = id.map_path(|path| path.strip_prefix("old_").unwrap_or(path).into());
+    /// assert!(std::ptr::eq(same.as_str(), id.as_str()));
+    /// ```
+    pub fn map_path<F>(&self, f: F) -> ResourceIdentifier<'a>
+    where
+        F: for<'s> FnOnce(&'s str) -> Cow<'s, str>,
+    {
+        let path = self.path();
+
+        match f(path) {
+            Cow::Borrowed(mapped) if mapped == path => self.clone(),
+            mapped => {
+                // The path is always a suffix of the full id.
+                let prefix = &self.id[..self.id.len() - path.len()];
+                Self {
+                    id: Cow::Owned(format!("{}{}", prefix, mapped)),
+                    kind: self.kind,
+                }
+            }
+        }
+    }
+
+    /// Returns the name of the file that stores the resource, including its
+    /// extension but not the directories it is in.
+    ///
+    /// See [`ResourceKind::file_name()`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::api::*;
+    /// let id = ResourceIdentifier::new(ResourceKind::TextureMeta, "minecraft:block/kelp");
+    /// assert_eq!(id.file_name(), "kelp.png.mcmeta");
+    ///
+    /// let id = ResourceIdentifier::block_model("stone");
+    /// assert_eq!(id.file_name(), "stone.json");
+    /// ```
+    pub fn file_name(&self) -> String {
+        let path = self.path();
+        let name = path.rsplit('/').next().unwrap_or(path);
+        self.kind.file_name(name)
+    }
+
     /// Returns what kind of resource is referenced by this id.
     pub fn kind(&self) -> ResourceKind {
         self.kind
@@ -242,6 +458,37 @@ impl<'a> ResourceIdentifier<'a> {
         }
     }
 
+    /// Returns the id of the model referenced by the given `parent` field of
+    /// the model with this id, or `None` if the parent is a builtin model.
+    ///
+    /// The parent is of the same kind as `self`, so the parent of a
+    /// [`BlockModel`][ResourceKind::BlockModel] is looked up among the block
+    /// models and the parent of an [`ItemModel`][ResourceKind::ItemModel] among
+    /// the item models.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::api::*;
+    /// let id = ResourceIdentifier::item_model("diamond_hoe");
+    ///
+    /// let parent = id.parent_location("item/handheld").unwrap();
+    /// assert_eq!(parent, ResourceIdentifier::item_model("minecraft:item/handheld"));
+    ///
+    /// let handheld = parent;
+    /// assert_eq!(handheld.parent_location("builtin/generated"), None);
+    /// ```
+    pub fn parent_location(&self, parent_id: &str) -> Option<ResourceIdentifier<'static>> {
+        if ModelIdentifier::is_builtin(parent_id) {
+            None
+        } else {
+            Some(ResourceIdentifier::new_owned(
+                self.kind,
+                String::from(parent_id),
+            ))
+        }
+    }
+
     /// Returns a new id with a canonical representation (i.e.,
     /// containing an explicit namespace).
     ///
@@ -380,6 +627,21 @@ impl<'a> PartialEq for ResourceIdentifier<'a> {
 
 impl<'a> Eq for ResourceIdentifier<'a> {}
 
+impl<'a> PartialOrd for ResourceIdentifier<'a> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<'a> Ord for ResourceIdentifier<'a> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.kind
+            .cmp(&other.kind)
+            .then_with(|| self.namespace().cmp(other.namespace()))
+            .then_with(|| self.path().cmp(other.path()))
+    }
+}
+
 impl<'a> Hash for ResourceIdentifier<'a> {
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
         self.namespace().hash(state);
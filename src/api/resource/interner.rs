@@ -0,0 +1,117 @@
+use std::{
+    collections::HashSet,
+    fmt,
+    sync::{Arc, Mutex},
+};
+
+use crate::api::{ResourceIdentifier, ResourceKind};
+
+/// Deduplicates the strings of [`ResourceIdentifier`]s.
+///
+/// Tools that hold on to many owned identifiers at once (e.g., the full graph
+/// of references between the models of a pack) end up storing the same string
+/// many times. Interning each identifier returns an [`InternedIdentifier`]
+/// that shares a single allocation with every other handle to the same id, and
+/// that is cheap to clone.
+///
+/// Identifiers are interned in their [canonical form], so `"stone"` and
+/// `"minecraft:stone"` share the same handle. The interner is safe to use from
+/// multiple threads.
+///
+/// [canonical form]: ResourceIdentifier::to_canonical
+///
+/// # Example
+///
+/// ```
+/// # use minecraft_assets::api::*;
+/// let interner = ResourceInterner::new();
+///
+/// let a = interner.intern(&ResourceIdentifier::block_model("stone"));
+/// let b = interner.intern(&ResourceIdentifier::block_model("minecraft:block/stone"));
+///
+/// assert_eq!(a, b);
+/// assert_eq!(a.as_str(), "minecraft:block/stone");
+///
+/// // Both handles point to the same string.
+/// assert!(std::ptr::eq(a.as_str(), b.as_str()));
+/// assert_eq!(interner.len(), 1);
+/// ```
+#[derive(Default)]
+pub struct ResourceInterner {
+    strings: Mutex<HashSet<Arc<str>>>,
+}
+
+impl ResourceInterner {
+    /// Returns a new, empty interner.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns a handle to the interned string of the given id, allocating it
+    /// only if it has not been interned before.
+    pub fn intern(&self, id: &ResourceIdentifier) -> InternedIdentifier {
+        let canonical = id.to_canonical();
+        let mut strings = self.strings.lock().unwrap();
+
+        let string = match strings.get(canonical.as_str()) {
+            Some(string) => Arc::clone(string),
+            None => {
+                let string: Arc<str> = Arc::from(canonical.as_str());
+                strings.insert(Arc::clone(&string));
+                string
+            }
+        };
+
+        InternedIdentifier {
+            id: string,
+            kind: id.kind(),
+        }
+    }
+
+    /// Returns the number of distinct strings that have been interned.
+    pub fn len(&self) -> usize {
+        self.strings.lock().unwrap().len()
+    }
+
+    /// Returns `true` if no strings have been interned.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// A cheap-to-clone handle to a [`ResourceIdentifier`] whose string is owned
+/// by a [`ResourceInterner`].
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct InternedIdentifier {
+    id: Arc<str>,
+    kind: ResourceKind,
+}
+
+impl InternedIdentifier {
+    /// Returns the underlying identifier as a string slice.
+    pub fn as_str(&self) -> &str {
+        &self.id
+    }
+
+    /// Returns what kind of resource is referenced by this id.
+    pub fn kind(&self) -> ResourceKind {
+        self.kind
+    }
+
+    /// Returns a [`ResourceIdentifier`] that borrows the interned string.
+    pub fn as_identifier(&self) -> ResourceIdentifier<'_> {
+        ResourceIdentifier::new(self.kind, &self.id)
+    }
+}
+
+impl fmt::Debug for InternedIdentifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.as_identifier(), f)
+    }
+}
+
+impl fmt::Display for InternedIdentifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.id, f)
+    }
+}
@@ -1,7 +1,26 @@
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
 use crate::api::ResourceCategory;
 
 /// The type of a resource.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+///
+/// Kinds are ordered as they are declared, and (de)serialize as their name in
+/// `snake_case` (e.g., `"block_model"`).
+///
+/// More kinds will be added as Minecraft introduces new types of resources, so
+/// this enum is marked `#[non_exhaustive]`. Directories that do not hold any
+/// known kind of resource can be discovered with
+/// [`AssetPack::enumerate_unknown()`][crate::api::AssetPack::enumerate_unknown].
+///
+/// The `Worldgen*` kinds of data pack resources have no schema in this crate.
+/// They can be enumerated and loaded as raw bytes (e.g., with
+/// [`LoadResource::load_resource()`][crate::api::LoadResource::load_resource]),
+/// but are not parsed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
 pub enum ResourceKind {
     /// Resources (`.json`) in `assets/<namespace>/blockstates/`.
     BlockStates,
@@ -17,9 +36,189 @@ pub enum ResourceKind {
 
     /// Resources (`.png.mcmeta`) in `assets/<namespace>/textures/`.
     TextureMeta,
+
+    /// Resources (`.json`) in `assets/<namespace>/font/`.
+    Font,
+
+    /// Resources (`.json`) in `assets/<namespace>/particles/`.
+    Particle,
+
+    /// Resources (`.json`) in `assets/<namespace>/atlases/`.
+    Atlas,
+
+    /// Resources (`.properties`) in `assets/<namespace>/optifine/`.
+    ///
+    /// These are not part of vanilla Minecraft. They are read by the OptiFine
+    /// mod, e.g., to configure connected textures.
+    OptifineProperties,
+
+    /// Resources (`.json`) in `data/<namespace>/recipes/`.
+    Recipe,
+
+    /// Resources (`.json`) in `data/<namespace>/tags/`.
+    ///
+    /// The path of a tag includes the type of the tag as its first component,
+    /// e.g., `blocks/logs` or `items/planks`.
+    Tag,
+
+    /// Resources (`.json`) in `data/<namespace>/worldgen/biome/`.
+    WorldgenBiome,
+
+    /// Resources (`.json`) in `data/<namespace>/worldgen/configured_carver/`.
+    WorldgenConfiguredCarver,
+
+    /// Resources (`.json`) in `data/<namespace>/worldgen/configured_feature/`.
+    WorldgenConfiguredFeature,
+
+    /// Resources (`.json`) in `data/<namespace>/worldgen/placed_feature/`.
+    WorldgenPlacedFeature,
+
+    /// Resources (`.json`) in `data/<namespace>/worldgen/structure/`.
+    WorldgenStructure,
+
+    /// Resources (`.json`) in `data/<namespace>/worldgen/noise_settings/`.
+    WorldgenNoiseSettings,
+
+    /// Resources of a type that this crate does not know about, e.g., one
+    /// added by a mod or by a future version of Minecraft.
+    ///
+    /// Resources of this kind can be enumerated and loaded as raw bytes like
+    /// any other, but are not parsed. This kind is not included in
+    /// [`all()`][Self::all], and cannot be (de)serialized.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::api::*;
+    /// use std::path::Path;
+    ///
+    /// // `assets/<namespace>/postprocessing/*.json`
+    /// let kind = ResourceKind::Custom {
+    ///     category: ResourceCategory::Assets,
+    ///     directory: "postprocessing",
+    ///     extension: "json",
+    /// };
+    ///
+    /// assert_eq!(kind.directory(), "postprocessing");
+    /// assert_eq!(
+    ///     kind.full_directory("mymod"),
+    ///     Path::new("assets/mymod/postprocessing")
+    /// );
+    /// ```
+    //
+    // The `&'static str` fields are skipped too, so that serde does not
+    // require `'de: 'static` to deserialize the other kinds.
+    #[serde(skip)]
+    Custom {
+        /// Whether the resources are in `assets/` or `data/`.
+        category: ResourceCategory,
+
+        /// The path relative to `assets/<namespace>/` or `data/<namespace>/`
+        /// of the directory in which the resources reside, using `/` as the
+        /// separator.
+        #[serde(skip)]
+        directory: &'static str,
+
+        /// The extension of the resources' files, without the leading `.`.
+        #[serde(skip)]
+        extension: &'static str,
+    },
 }
 
 impl ResourceKind {
+    /// Every kind of resource.
+    pub(crate) const ALL: &'static [ResourceKind] = &[
+        Self::BlockStates,
+        Self::BlockModel,
+        Self::ItemModel,
+        Self::Texture,
+        Self::TextureMeta,
+        Self::Font,
+        Self::Particle,
+        Self::Atlas,
+        Self::OptifineProperties,
+        Self::Recipe,
+        Self::Tag,
+        Self::WorldgenBiome,
+        Self::WorldgenConfiguredCarver,
+        Self::WorldgenConfiguredFeature,
+        Self::WorldgenPlacedFeature,
+        Self::WorldgenStructure,
+        Self::WorldgenNoiseSettings,
+    ];
+
+    /// Returns every kind of resource, in the order that they are declared.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::api::*;
+    /// assert_eq!(ResourceKind::all()[0], ResourceKind::BlockStates);
+    /// assert!(ResourceKind::all().contains(&ResourceKind::Tag));
+    /// ```
+    pub fn all() -> &'static [ResourceKind] {
+        Self::ALL
+    }
+
+    /// Returns the kind of resource that resides in the given directory
+    /// relative to `assets/<namespace>/` or `data/<namespace>/`, or `None` if
+    /// no kind of resource does.
+    ///
+    /// This is the inverse of [`directory()`][Self::directory]. Since both
+    /// [`Texture`][Self::Texture] and [`TextureMeta`][Self::TextureMeta]
+    /// resources reside in `textures`, that directory maps to `Texture`. Use
+    /// [`from_directory_and_extension()`][Self::from_directory_and_extension]
+    /// to tell them apart.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::api::*;
+    /// assert_eq!(
+    ///     ResourceKind::from_directory("models/block"),
+    ///     Some(ResourceKind::BlockModel)
+    /// );
+    /// assert_eq!(
+    ///     ResourceKind::from_directory("textures"),
+    ///     Some(ResourceKind::Texture)
+    /// );
+    /// assert_eq!(ResourceKind::from_directory("models"), None);
+    /// ```
+    pub fn from_directory(directory: &str) -> Option<Self> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|kind| kind.directory() == directory)
+    }
+
+    /// Returns the kind of resource that resides in the given directory
+    /// relative to `assets/<namespace>/` or `data/<namespace>/` and uses the
+    /// given file extension, or `None` if no kind of resource does.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::api::*;
+    /// assert_eq!(
+    ///     ResourceKind::from_directory_and_extension("textures", "png"),
+    ///     Some(ResourceKind::Texture)
+    /// );
+    /// assert_eq!(
+    ///     ResourceKind::from_directory_and_extension("textures", "png.mcmeta"),
+    ///     Some(ResourceKind::TextureMeta)
+    /// );
+    /// assert_eq!(
+    ///     ResourceKind::from_directory_and_extension("textures", "json"),
+    ///     None
+    /// );
+    /// ```
+    pub fn from_directory_and_extension(directory: &str, extension: &str) -> Option<Self> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|kind| kind.directory() == directory && kind.extension() == extension)
+    }
+
     /// Returns the category of this resource type (assets or data).
     pub fn category(&self) -> ResourceCategory {
         match self {
@@ -27,7 +226,20 @@ impl ResourceKind {
             | Self::BlockModel
             | Self::ItemModel
             | Self::Texture
-            | Self::TextureMeta => ResourceCategory::Assets,
+            | Self::TextureMeta
+            | Self::Font
+            | Self::Particle
+            | Self::Atlas
+            | Self::OptifineProperties => ResourceCategory::Assets,
+            Self::Recipe
+            | Self::Tag
+            | Self::WorldgenBiome
+            | Self::WorldgenConfiguredCarver
+            | Self::WorldgenConfiguredFeature
+            | Self::WorldgenPlacedFeature
+            | Self::WorldgenStructure
+            | Self::WorldgenNoiseSettings => ResourceCategory::Data,
+            Self::Custom { category, .. } => *category,
         }
     }
 
@@ -48,12 +260,68 @@ impl ResourceKind {
     /// ```
     pub fn extension(&self) -> &'static str {
         match self {
-            Self::BlockStates | Self::BlockModel | Self::ItemModel => "json",
+            Self::BlockStates
+            | Self::BlockModel
+            | Self::ItemModel
+            | Self::Font
+            | Self::Particle
+            | Self::Atlas
+            | Self::Recipe
+            | Self::Tag
+            | Self::WorldgenBiome
+            | Self::WorldgenConfiguredCarver
+            | Self::WorldgenConfiguredFeature
+            | Self::WorldgenPlacedFeature
+            | Self::WorldgenStructure
+            | Self::WorldgenNoiseSettings => "json",
             Self::Texture => "png",
             Self::TextureMeta => "png.mcmeta",
+            Self::OptifineProperties => "properties",
+            Self::Custom { extension, .. } => extension,
         }
     }
 
+    /// Returns the name of the file that stores the resource of this type with
+    /// the given path, i.e., the path followed by `.` and the
+    /// [`extension()`][Self::extension].
+    ///
+    /// Every file name or path of a resource is built with this method, so
+    /// that the double extension of [`TextureMeta`][Self::TextureMeta] is
+    /// handled the same way everywhere.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::api::*;
+    /// let kind = ResourceKind::TextureMeta;
+    /// assert_eq!(kind.file_name("block/kelp"), "block/kelp.png.mcmeta");
+    /// ```
+    pub fn file_name(&self, path: &str) -> String {
+        format!("{}.{}", path, self.extension())
+    }
+
+    /// Returns the path of the resource of this type that is stored in the
+    /// file with the given name, or `None` if the file does not have this
+    /// type's [`extension()`][Self::extension].
+    ///
+    /// This is the inverse of [`file_name()`][Self::file_name].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::api::*;
+    /// let kind = ResourceKind::Texture;
+    /// assert_eq!(kind.strip_extension("block/kelp.png"), Some("block/kelp"));
+    /// assert_eq!(kind.strip_extension("block/kelp.png.mcmeta"), None);
+    /// assert_eq!(kind.strip_extension("block/kelppng"), None);
+    /// ```
+    pub fn strip_extension<'a>(&self, file_name: &'a str) -> Option<&'a str> {
+        file_name
+            .strip_suffix(self.extension())?
+            .strip_suffix('.')
+            .filter(|path| !path.is_empty())
+    }
+
     /// Returns the path relative to `assets/<namespace>/` or
     /// `data/<namespace>/` in which resources of this type reside.
     pub fn directory(&self) -> &'static str {
@@ -62,6 +330,53 @@ impl ResourceKind {
             Self::BlockModel => "models/block",
             Self::ItemModel => "models/item",
             Self::Texture | Self::TextureMeta => "textures",
+            Self::Font => "font",
+            Self::Particle => "particles",
+            Self::Atlas => "atlases",
+            Self::OptifineProperties => "optifine",
+            Self::Recipe => "recipes",
+            Self::Tag => "tags",
+            Self::WorldgenBiome => "worldgen/biome",
+            Self::WorldgenConfiguredCarver => "worldgen/configured_carver",
+            Self::WorldgenConfiguredFeature => "worldgen/configured_feature",
+            Self::WorldgenPlacedFeature => "worldgen/placed_feature",
+            Self::WorldgenStructure => "worldgen/structure",
+            Self::WorldgenNoiseSettings => "worldgen/noise_settings",
+            Self::Custom { directory, .. } => directory,
         }
     }
+
+    /// Returns the path relative to the root of a pack of the directory in
+    /// which resources of this type reside for the given namespace.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::api::*;
+    /// use std::path::Path;
+    ///
+    /// let kind = ResourceKind::BlockModel;
+    /// assert_eq!(
+    ///     kind.full_directory("minecraft"),
+    ///     Path::new("assets/minecraft/models/block")
+    /// );
+    ///
+    /// let kind = ResourceKind::Recipe;
+    /// assert_eq!(
+    ///     kind.full_directory("foo"),
+    ///     Path::new("data/foo/recipes")
+    /// );
+    ///
+    /// let kind = ResourceKind::WorldgenBiome;
+    /// assert_eq!(
+    ///     kind.full_directory("foo"),
+    ///     Path::new("data/foo/worldgen/biome")
+    /// );
+    /// ```
+    pub fn full_directory(&self, namespace: &str) -> PathBuf {
+        let mut path = PathBuf::from(self.category().directory());
+        path.push(namespace);
+        path.push(self.directory());
+        path
+    }
 }
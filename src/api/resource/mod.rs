@@ -1,11 +1,13 @@
 mod category;
 mod identifier;
+mod interner;
 mod kind;
 mod model_identifier;
 mod path;
 
 pub use category::ResourceCategory;
 pub use identifier::{ResourceIdentifier, MINECRAFT_NAMESPACE};
+pub use interner::{InternedIdentifier, ResourceInterner};
 pub use kind::ResourceKind;
-pub use model_identifier::ModelIdentifier;
+pub use model_identifier::{Builtin, ModelIdentifier};
 pub use path::ResourcePath;
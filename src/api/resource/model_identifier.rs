@@ -1,3 +1,8 @@
+use crate::{
+    api::ResourceKind,
+    versions::{self, Version},
+};
+
 /// Helper methods for dealing with model identifiers.
 ///
 /// # Why does this exist?
@@ -39,7 +44,56 @@ impl ModelIdentifier {
             .unwrap_or_else(|| id)
     }
 
-    pub(crate) fn is_builtin(id: &str) -> bool {
+    /// Returns the form in which blockstates files for the given version
+    /// refer to the model with the given name.
+    ///
+    /// This is the opposite of [`model_name()`][Self::model_name], and is a
+    /// shorthand for [`versions::model_reference()`].
+    ///
+    /// [`versions::model_reference()`]: crate::versions::model_reference
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::api::*;
+    /// use minecraft_assets::versions::Version;
+    ///
+    /// let kind = ResourceKind::BlockModel;
+    ///
+    /// assert_eq!(ModelIdentifier::to_versioned("oak_planks", kind, Version::V1_12), "oak_planks");
+    /// assert_eq!(
+    ///     ModelIdentifier::to_versioned("oak_planks", kind, Version::V1_16),
+    ///     "block/oak_planks"
+    /// );
+    /// assert_eq!(
+    ///     ModelIdentifier::to_versioned("block/oak_planks", kind, Version::V1_16_2),
+    ///     "minecraft:block/oak_planks"
+    /// );
+    /// ```
+    pub fn to_versioned(name: &str, kind: ResourceKind, version: Version) -> String {
+        versions::model_reference(name, kind, version)
+    }
+
+    /// Returns `true` if the given model id refers to a builtin model, which
+    /// has no corresponding file (e.g., `builtin/generated` or
+    /// `builtin/entity`).
+    ///
+    /// This can be used to stop walking the chain of a model's parents, since
+    /// a builtin model never has a parent of its own.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::api::*;
+    /// assert!(ModelIdentifier::is_builtin("builtin/generated"));
+    /// assert!(ModelIdentifier::is_builtin("minecraft:builtin/entity"));
+    ///
+    /// assert!(!ModelIdentifier::is_builtin("item/generated"));
+    /// assert!(!ModelIdentifier::is_builtin("builtin"));
+    /// ```
+    pub fn is_builtin(id: &str) -> bool {
+        let id = id.strip_prefix("minecraft:").unwrap_or(id);
+
         match Self::slash_position(id) {
             Some(index) => {
                 let prefix = &id[..index];
@@ -49,7 +103,104 @@ impl ModelIdentifier {
         }
     }
 
+    /// Returns which [`Builtin`] model the given model id refers to, or `None`
+    /// if it does not refer to a builtin model.
+    ///
+    /// See [`is_builtin()`][Self::is_builtin].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::api::*;
+    /// assert_eq!(
+    ///     ModelIdentifier::builtin_kind("builtin/generated"),
+    ///     Some(Builtin::Generated)
+    /// );
+    /// assert_eq!(
+    ///     ModelIdentifier::builtin_kind("minecraft:builtin/entity"),
+    ///     Some(Builtin::Entity)
+    /// );
+    /// assert_eq!(
+    ///     ModelIdentifier::builtin_kind("builtin/fancy"),
+    ///     Some(Builtin::Other(String::from("fancy")))
+    /// );
+    ///
+    /// assert_eq!(ModelIdentifier::builtin_kind("block/cube"), None);
+    /// ```
+    pub fn builtin_kind(id: &str) -> Option<Builtin> {
+        if !Self::is_builtin(id) {
+            return None;
+        }
+
+        let builtin = match Self::model_name(id) {
+            "generated" => Builtin::Generated,
+            "entity" => Builtin::Entity,
+            "compass" => Builtin::Compass,
+            "clock" => Builtin::Clock,
+            "missing" => Builtin::Missing,
+            name => Builtin::Other(String::from(name)),
+        };
+
+        Some(builtin)
+    }
+
     fn slash_position(id: &str) -> Option<usize> {
         id.chars().position(|c| c == '/')
     }
 }
+
+/// A builtin model, which has no corresponding file and is instead rendered by
+/// the game's code.
+///
+/// A chain of item model parents may end at a builtin model, which tells the
+/// renderer how to draw the item. See [`ModelIdentifier::builtin_kind()`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Builtin {
+    /// `builtin/generated`: the item is drawn as a flat sprite, extruded from
+    /// the textures of its [`layers`].
+    ///
+    /// [`layers`]: crate::schemas::models::Textures::layers
+    Generated,
+
+    /// `builtin/entity`: the item is drawn like an entity or a block entity
+    /// (e.g., a chest, a shield, or a banner).
+    Entity,
+
+    /// `builtin/compass`: the compass, whose sprite is picked from its
+    /// animation frames based on where it points (before 1.9).
+    Compass,
+
+    /// `builtin/clock`: the clock, whose sprite is picked from its animation
+    /// frames based on the time of day (before 1.9).
+    Clock,
+
+    /// `builtin/missing`: the missing model.
+    Missing,
+
+    /// Any other builtin model, by name (e.g., `"fancy"` for
+    /// `builtin/fancy`).
+    Other(String),
+}
+
+impl Builtin {
+    /// Returns the name of the builtin model, i.e., the part of its id after
+    /// `builtin/`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::api::*;
+    /// assert_eq!(Builtin::Generated.name(), "generated");
+    /// assert_eq!(Builtin::Other(String::from("fancy")).name(), "fancy");
+    /// ```
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Generated => "generated",
+            Self::Entity => "entity",
+            Self::Compass => "compass",
+            Self::Clock => "clock",
+            Self::Missing => "missing",
+            Self::Other(name) => name,
+        }
+    }
+}
@@ -20,9 +20,9 @@ impl ResourcePath {
     pub fn for_resource(root: impl AsRef<Path>, resource: &ResourceIdentifier) -> Self {
         let mut path = Self::for_kind(root, resource.namespace(), resource.kind());
 
-        path.push(resource.path());
+        path.push(resource.kind().file_name(resource.path()));
 
-        Self(path.with_extension(resource.kind().extension()))
+        path
     }
 
     /// Returns the full path to the directory that contains resources of the
@@ -35,14 +35,7 @@ impl ResourcePath {
     /// not point to an existing directory. This method simply computes what the
     /// path should be for a given resource type.
     pub fn for_kind(root: impl AsRef<Path>, namespace: &str, kind: ResourceKind) -> Self {
-        let mut path = PathBuf::from(root.as_ref());
-
-        // `assets/` or `data/`.
-        path.push(kind.category().directory());
-        path.push(namespace);
-        path.push(kind.directory());
-
-        Self(path)
+        Self(root.as_ref().join(kind.full_directory(namespace)))
     }
 
     /// Consumes `self` and returns the inner [`PathBuf`].
@@ -0,0 +1,183 @@
+//! Serde-(de)serializable data types for `assets/<namespace>/atlases/*.json`.
+//!
+//! Start here: [`AtlasDefinition`].
+//!
+//! Atlas files were introduced in Minecraft 1.19.3. See
+//! <https://minecraft.fandom.com/wiki/Resource_pack#Atlases>.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+/// A texture atlas as stored in the `assets/<namespace>/atlases/` directory.
+///
+/// An atlas is built by applying each of its [`AtlasSource`]s in order. Each
+/// source adds textures to (or removes textures from) the atlas.
+///
+/// See also the corresponding section of the [wiki page].
+///
+/// [wiki page]: <https://minecraft.fandom.com/wiki/Resource_pack#Atlases>
+///
+/// # Example
+///
+/// ```
+/// # use minecraft_assets::schemas::atlas::*;
+/// let json = r#"{
+///     "sources": [
+///         { "type": "directory", "source": "block", "prefix": "block/" },
+///         { "type": "minecraft:single", "resource": "minecraft:misc/enchanted_glint_item" }
+///     ]
+/// }"#;
+///
+/// let atlas: AtlasDefinition = serde_json::from_str(json).unwrap();
+///
+/// assert_eq!(
+///     atlas.sources[0],
+///     AtlasSource::Directory {
+///         source: String::from("block"),
+///         prefix: String::from("block/"),
+///     }
+/// );
+/// assert_eq!(
+///     atlas.sources[1],
+///     AtlasSource::Single {
+///         resource: String::from("minecraft:misc/enchanted_glint_item"),
+///         sprite: None,
+///     }
+/// );
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct AtlasDefinition {
+    /// The sources of the textures in the atlas, applied in order.
+    pub sources: Vec<AtlasSource>,
+}
+
+/// A source of textures in an [`AtlasDefinition`].
+///
+/// The `minecraft:` prefix of the `"type"` field may be omitted.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum AtlasSource {
+    /// Adds every texture in a directory and its subdirectories.
+    #[serde(alias = "minecraft:directory")]
+    Directory {
+        /// The directory to search, relative to `textures/`.
+        source: String,
+
+        /// The string prepended to the name of each texture when it is added to
+        /// the atlas.
+        prefix: String,
+    },
+
+    /// Adds a single texture.
+    #[serde(alias = "minecraft:single")]
+    Single {
+        /// The [resource location] of the texture, relative to `textures/`.
+        ///
+        /// [resource location]: <https://minecraft.fandom.com/wiki/Model#File_path>
+        resource: String,
+
+        /// The name of the texture in the atlas. Defaults to `resource`.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        sprite: Option<String>,
+    },
+
+    /// Removes the textures added by previous sources whose ids match a
+    /// pattern.
+    #[serde(alias = "minecraft:filter")]
+    Filter {
+        /// The pattern that the removed textures match.
+        pattern: AtlasFilterPattern,
+    },
+
+    /// Adds regions of a single texture as separate textures.
+    #[serde(alias = "minecraft:unstitch")]
+    Unstitch {
+        /// The [resource location] of the texture, relative to `textures/`.
+        ///
+        /// [resource location]: <https://minecraft.fandom.com/wiki/Model#File_path>
+        resource: String,
+
+        /// The number of units that the width of the texture is divided into.
+        /// The regions are given in these units (`1.0` - default).
+        #[serde(default = "AtlasSource::default_divisor")]
+        divisor_x: f64,
+
+        /// The number of units that the height of the texture is divided into.
+        /// The regions are given in these units (`1.0` - default).
+        #[serde(default = "AtlasSource::default_divisor")]
+        divisor_y: f64,
+
+        /// The regions to add to the atlas.
+        regions: Vec<UnstitchRegion>,
+    },
+
+    /// Adds variants of textures that are recolored with a set of palettes.
+    #[serde(alias = "minecraft:paletted_permutations")]
+    PalettedPermutations {
+        /// The [resource locations] of the textures to recolor, relative to
+        /// `textures/`.
+        ///
+        /// [resource locations]: <https://minecraft.fandom.com/wiki/Model#File_path>
+        textures: Vec<String>,
+
+        /// The [resource location] of the palette that the colors of the
+        /// textures are taken from.
+        ///
+        /// [resource location]: <https://minecraft.fandom.com/wiki/Model#File_path>
+        palette_key: String,
+
+        /// Maps the suffix appended to the name of each recolored texture to
+        /// the [resource location] of the palette it is recolored with.
+        ///
+        /// [resource location]: <https://minecraft.fandom.com/wiki/Model#File_path>
+        permutations: HashMap<String, String>,
+    },
+
+    /// Any other type of source that is not yet supported by this crate.
+    ///
+    /// This variant cannot be serialized.
+    #[serde(other, skip_serializing)]
+    Other,
+}
+
+impl AtlasSource {
+    pub(crate) const fn default_divisor() -> f64 {
+        1.0
+    }
+}
+
+/// The pattern of a [`Filter`][AtlasSource::Filter] source.
+///
+/// Each field is a regular expression. A field that is absent matches
+/// anything.
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq, Eq)]
+pub struct AtlasFilterPattern {
+    /// The pattern that the namespace of a texture id must match.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub namespace: Option<String>,
+
+    /// The pattern that the path of a texture id must match.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+}
+
+/// A region of a texture used by an [`Unstitch`][AtlasSource::Unstitch]
+/// source.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct UnstitchRegion {
+    /// The name of the texture in the atlas.
+    pub sprite: String,
+
+    /// The position of the left edge of the region.
+    pub x: f64,
+
+    /// The position of the top edge of the region.
+    pub y: f64,
+
+    /// The width of the region.
+    pub width: f64,
+
+    /// The height of the region.
+    pub height: f64,
+}
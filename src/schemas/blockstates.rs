@@ -5,7 +5,10 @@
 //!
 //! See <https://minecraft.fandom.com/wiki/Model#Block_states>.
 
-use std::collections::HashMap;
+use std::{
+    collections::{BTreeMap, HashMap},
+    ops::{Deref, DerefMut},
+};
 
 use serde::{Deserialize, Serialize};
 
@@ -25,6 +28,34 @@ use serde::{Deserialize, Serialize};
 /// [doors]: https://minecraft.fandom.com/wiki/Door
 /// [block state]: https://minecraft.fandom.com/wiki/Block_state
 /// [wiki page]: <https://minecraft.fandom.com/wiki/Model#Block_states>
+///
+/// # Unknown Keys
+///
+/// Modded and experimental block states files sometimes contain top-level
+/// keys other than `variants` or `multipart`, such as `"forge_marker"` or
+/// `"properties"`. These keys are ignored, so such files still parse as long
+/// as their `variants` or `multipart` are in the vanilla format.
+///
+/// Since `BlockStates` is an untagged enum, the ignored keys are not kept, and
+/// are lost when the block states are serialized again. For the same reason, a
+/// file that has both `variants` and `multipart` parses as
+/// [`Variants`][Self::Variants] and its `multipart` is ignored.
+///
+/// ```
+/// # use minecraft_assets::schemas::BlockStates;
+/// let json = r#"{
+///     "forge_marker": 1,
+///     "variants": { "": { "model": "block/stone" } }
+/// }"#;
+///
+/// let states: BlockStates = serde_json::from_str(json).unwrap();
+///
+/// assert_eq!(states.variants().unwrap()[""].models()[0].model, "block/stone");
+/// assert_eq!(
+///     serde_json::to_string(&states).unwrap(),
+///     r#"{"variants":{"":{"model":"block/stone","x":0,"y":0,"uvlock":false,"weight":1}}}"#
+/// );
+/// ```
 #[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
 #[serde(untagged)]
 pub enum BlockStates {
@@ -37,7 +68,10 @@ pub enum BlockStates {
     /// A block with just one variant uses `""` as the name for its variant.
     Variants {
         /// Holds all the variants of the block by name.
-        variants: HashMap<String, Variant>,
+        ///
+        /// The variants are sorted by name, so they are always serialized in
+        /// the same order.
+        variants: BTreeMap<String, Variant>,
     },
 
     /// Another way of representing the different states of a block.
@@ -52,11 +86,26 @@ pub enum BlockStates {
 }
 
 impl BlockStates {
+    /// Parses block states from the raw contents of a block states file.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::schemas::BlockStates;
+    /// let json = br#"{ "variants": { "": { "model": "block/stone" } } }"#;
+    ///
+    /// let states = BlockStates::from_slice(json).unwrap();
+    /// assert_eq!(states.variants().unwrap()[""].models()[0].model, "block/stone");
+    /// ```
+    pub fn from_slice(bytes: &[u8]) -> serde_json::Result<Self> {
+        serde_json::from_slice(bytes)
+    }
+
     /// Returns the mapping from block states to [`Variant`]s, or `None` if the
     /// block states are specified as [`Multipart`].
     ///
     /// [`Multipart`]: Self::Multipart
-    pub fn variants(&self) -> Option<&HashMap<String, Variant>> {
+    pub fn variants(&self) -> Option<&BTreeMap<String, Variant>> {
         match self {
             Self::Variants { ref variants } => Some(variants),
             Self::Multipart { .. } => None,
@@ -76,6 +125,56 @@ impl BlockStates {
         }
     }
 
+    /// Returns an iterator over every [`ModelProperties`] in the block states,
+    /// whether they are specified as [`Variants`] or as [`Multipart`] cases.
+    ///
+    /// The same model may be referenced more than once.
+    ///
+    /// [`Variants`]: Self::Variants
+    /// [`Multipart`]: Self::Multipart
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::schemas::BlockStates;
+    /// let json = r#"{
+    ///     "multipart": [
+    ///         { "apply": { "model": "block/fence_post" } },
+    ///         {
+    ///             "when": { "north": "true" },
+    ///             "apply": [
+    ///                 { "model": "block/fence_side" },
+    ///                 { "model": "block/fence_side_alt" }
+    ///             ]
+    ///         }
+    ///     ]
+    /// }"#;
+    ///
+    /// let states: BlockStates = serde_json::from_str(json).unwrap();
+    /// let models: Vec<_> = states.all_models().map(|props| props.model.as_str()).collect();
+    ///
+    /// assert_eq!(
+    ///     models,
+    ///     ["block/fence_post", "block/fence_side", "block/fence_side_alt"]
+    /// );
+    /// ```
+    pub fn all_models(&self) -> impl Iterator<Item = &ModelProperties> {
+        let variants: Box<dyn Iterator<Item = &Variant>> = match self {
+            Self::Variants { variants } => Box::new(variants.values()),
+            Self::Multipart { cases } => Box::new(cases.iter().map(|case| &case.apply)),
+        };
+
+        variants.flat_map(Variant::models)
+    }
+
+    /// Checks the rotations of every model in the block states with
+    /// [`ModelProperties::validate()`].
+    ///
+    /// Returns the first invalid rotation as the error.
+    pub fn validate(&self) -> Result<(), i32> {
+        self.all_models().try_for_each(ModelProperties::validate)
+    }
+
     /// Consumes `self` and returns a new [`BlockStates::Multipart`] where all
     /// of the [`Variants`] have been converted to an equivalent [`Case`]
     ///
@@ -87,11 +186,7 @@ impl BlockStates {
 
             Self::Variants { variants } => {
                 if variants.len() == 1 {
-                    let variant = variants
-                        .into_iter()
-                        .map(|(_, variant)| variant)
-                        .next()
-                        .unwrap();
+                    let variant = variants.into_values().next().unwrap();
 
                     let case = multipart::Case {
                         when: None,
@@ -102,17 +197,15 @@ impl BlockStates {
                 } else {
                     variants
                         .into_iter()
-                        .map(|(state_values, variant)| {
-                            let state_values: HashMap<String, multipart::StateValue> = state_values
-                                .split(',')
-                                .map(|state_value| {
-                                    let split: Vec<&str> = state_value.split('=').collect();
-                                    (split[0], split[1])
-                                })
-                                .map(|(state, value)| {
-                                    (String::from(state), multipart::StateValue::from(value))
-                                })
-                                .collect();
+                        .map(|(key, variant)| {
+                            let state_values: HashMap<String, multipart::StateValue> =
+                                Self::parse_variant_key(&key)
+                                    .properties
+                                    .into_iter()
+                                    .map(|(state, value)| {
+                                        (state, multipart::StateValue::from(value))
+                                    })
+                                    .collect();
 
                             let condition = multipart::Condition { and: state_values };
 
@@ -130,6 +223,117 @@ impl BlockStates {
     }
 }
 
+impl BlockStates {
+    /// Parses the name of a variant in [`BlockStates::Variants`] into a map
+    /// from property name to property value.
+    ///
+    /// The key is split on commas into `property=value` pairs. Entries without
+    /// an `=` are ignored, so the empty key `""` used by single-variant blocks
+    /// (and the `"normal"` key used prior to 1.13) produce an empty map.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::schemas::blockstates::*;
+    /// let properties = BlockStates::parse_variant_key("facing=east,half=bottom");
+    ///
+    /// assert_eq!(properties.len(), 2);
+    /// assert_eq!(properties["facing"], "east");
+    /// assert_eq!(properties["half"], "bottom");
+    ///
+    /// let properties = BlockStates::parse_variant_key("");
+    /// assert!(properties.is_empty());
+    /// ```
+    pub fn parse_variant_key(key: &str) -> BlockStateProperties {
+        let properties = key
+            .split(',')
+            .filter_map(|state_value| state_value.split_once('='))
+            .map(|(state, value)| (String::from(state), String::from(value)))
+            .collect();
+
+        BlockStateProperties { properties }
+    }
+
+    /// Returns every [`Variant`] that applies to a block with the given
+    /// property values.
+    ///
+    /// For [`Variants`][Self::Variants], this is each variant whose name
+    /// (parsed with [`parse_variant_key()`][Self::parse_variant_key]) is fully
+    /// matched by `state`. For [`Multipart`][Self::Multipart], this is the
+    /// variant of each [`Case`] that [applies][multipart::Case::applies] to
+    /// `state`, in order.
+    ///
+    /// [`Case`]: multipart::Case
+    pub fn active_variants(&self, state: &HashMap<String, String>) -> Vec<&Variant> {
+        match self {
+            Self::Variants { variants } => variants
+                .iter()
+                .filter(|(key, _)| {
+                    Self::parse_variant_key(key)
+                        .iter()
+                        .all(|(name, value)| state.get(name) == Some(value))
+                })
+                .map(|(_, variant)| variant)
+                .collect(),
+
+            Self::Multipart { cases } => {
+                let state_values: Vec<(&str, multipart::StateValue)> = state
+                    .iter()
+                    .map(|(name, value)| {
+                        (name.as_str(), multipart::StateValue::from(value.as_str()))
+                    })
+                    .collect();
+
+                cases
+                    .iter()
+                    .filter(|case| {
+                        case.applies(state_values.iter().map(|(name, value)| (*name, value)))
+                    })
+                    .map(|case| &case.apply)
+                    .collect()
+            }
+        }
+    }
+
+    /// Returns the [`ModelProperties`] of every [`Variant`] that applies to a
+    /// block with the given property values.
+    ///
+    /// See [`active_variants()`][Self::active_variants]. If a variant lists
+    /// several weighted models, all of them are included.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::schemas::blockstates::*;
+    /// use maplit::hashmap;
+    ///
+    /// let blockstates: BlockStates = serde_json::from_str(r#"{
+    ///     "multipart": [
+    ///         { "apply": { "model": "block/fence_post" } },
+    ///         { "when": { "north": "true" }, "apply": { "model": "block/fence_side" } },
+    ///         { "when": { "east": true }, "apply": { "model": "block/fence_side", "y": 90 } }
+    ///     ]
+    /// }"#).unwrap();
+    ///
+    /// let state = hashmap! {
+    ///     String::from("north") => String::from("false"),
+    ///     String::from("east") => String::from("true"),
+    /// };
+    ///
+    /// let models = blockstates.active_models(&state);
+    /// assert_eq!(models.len(), 2);
+    /// assert_eq!(models[0].model, "block/fence_post");
+    /// assert_eq!(models[1].model, "block/fence_side");
+    /// assert_eq!(models[1].y, 90);
+    /// ```
+    pub fn active_models(&self, state: &HashMap<String, String>) -> Vec<&ModelProperties> {
+        self.active_variants(state)
+            .into_iter()
+            .flat_map(|variant| variant.models())
+            .collect()
+    }
+}
+
 impl Default for BlockStates {
     fn default() -> Self {
         Self::Variants {
@@ -138,11 +342,74 @@ impl Default for BlockStates {
     }
 }
 
+/// The values of a block's properties, as parsed from the name of a variant in
+/// [`BlockStates::Variants`].
+///
+/// See [`BlockStates::parse_variant_key()`].
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct BlockStateProperties {
+    /// The value of each property by name, ordered by name.
+    pub properties: BTreeMap<String, String>,
+}
+
+impl BlockStateProperties {
+    /// Returns the canonical variant name for these property values, i.e., the
+    /// `property=value` pairs sorted by property name and joined with commas.
+    ///
+    /// This is the inverse of [`BlockStates::parse_variant_key()`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::schemas::blockstates::*;
+    /// let properties = BlockStates::parse_variant_key("shape=straight,facing=east");
+    /// assert_eq!(properties.to_key(), "facing=east,shape=straight");
+    ///
+    /// let properties = BlockStates::parse_variant_key("");
+    /// assert_eq!(properties.to_key(), "");
+    /// ```
+    pub fn to_key(&self) -> String {
+        self.properties
+            .iter()
+            .map(|(state, value)| format!("{}={}", state, value))
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+}
+
+impl<K, V> FromIterator<(K, V)> for BlockStateProperties
+where
+    K: Into<String>,
+    V: Into<String>,
+{
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let properties = iter
+            .into_iter()
+            .map(|(k, v)| (k.into(), v.into()))
+            .collect();
+        Self { properties }
+    }
+}
+
+impl Deref for BlockStateProperties {
+    type Target = BTreeMap<String, String>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.properties
+    }
+}
+
+impl DerefMut for BlockStateProperties {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.properties
+    }
+}
+
 /// A block variant.
 ///
 /// Each variant can have **one model** or an **array of models** and contains
 /// their properties. If set to an array, the model is chosen randomly from the
-/// models contained in the array based on the `Model::weight` field.
+/// models contained in the array based on the [`ModelProperties::weight`] field.
 #[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
 #[serde(untagged)]
 pub enum Variant {
@@ -171,6 +438,97 @@ impl Variant {
             Self::Multiple(models) => &models[..],
         }
     }
+
+    /// Returns the distinct `(x, y)` rotation pairs used by the models of this
+    /// variant, in the order in which they first appear.
+    ///
+    /// Blocks like stone list the same models several times with different
+    /// rotations so that the orientation of each block is randomized. This
+    /// returns every orientation that may be chosen.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::schemas::blockstates::*;
+    /// let variant: Variant = serde_json::from_str(r#"[
+    ///     { "model": "block/stone" },
+    ///     { "model": "block/stone_mirrored" },
+    ///     { "model": "block/stone", "y": 180 },
+    ///     { "model": "block/stone_mirrored", "y": 180 }
+    /// ]"#).unwrap();
+    ///
+    /// assert_eq!(variant.distinct_rotations(), vec![(0, 0), (0, 180)]);
+    /// ```
+    pub fn distinct_rotations(&self) -> Vec<(i32, i32)> {
+        let mut rotations = Vec::new();
+
+        for model in self.models() {
+            let rotation = (model.x, model.y);
+            if !rotations.contains(&rotation) {
+                rotations.push(rotation);
+            }
+        }
+
+        rotations
+    }
+
+    /// Randomly chooses one of the [`ModelProperties`] of this variant, with
+    /// each model's probability of being chosen proportional to its
+    /// [`weight`][ModelProperties::weight].
+    ///
+    /// A [`Single`][Self::Single] variant always returns its one model. If the
+    /// weights of a [`Multiple`][Self::Multiple] variant sum to zero, the first
+    /// model is returned.
+    ///
+    /// **Requires the `rand` feature.**
+    ///
+    /// # Panics
+    ///
+    /// Panics if this is a [`Multiple`][Self::Multiple] variant with no models.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::schemas::blockstates::*;
+    /// let variant = Variant::Multiple(vec![
+    ///     ModelProperties {
+    ///         model: String::from("block/stone"),
+    ///         weight: 3,
+    ///         ..Default::default()
+    ///     },
+    ///     ModelProperties {
+    ///         model: String::from("block/stone_mirrored"),
+    ///         weight: 1,
+    ///         ..Default::default()
+    ///     },
+    /// ]);
+    ///
+    /// let model = variant.choose_weighted(&mut rand::thread_rng());
+    /// assert!(variant.models().contains(model));
+    /// ```
+    #[cfg(feature = "rand")]
+    pub fn choose_weighted<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> &ModelProperties {
+        let models = match self {
+            Self::Single(model) => return model,
+            Self::Multiple(models) => &models[..],
+        };
+
+        let total_weight: u64 = models.iter().map(|model| model.weight as u64).sum();
+        if total_weight == 0 {
+            return &models[0];
+        }
+
+        let mut choice = rng.gen_range(0..total_weight);
+        for model in models {
+            let weight = model.weight as u64;
+            if choice < weight {
+                return model;
+            }
+            choice -= weight;
+        }
+
+        unreachable!()
+    }
 }
 
 /// Contains the properties of a model that is used to render all or part of a
@@ -235,6 +593,31 @@ impl ModelProperties {
     pub(crate) const fn default_weight() -> u32 {
         1
     }
+
+    /// Checks that the [`x`][Self::x] and [`y`][Self::y] rotations are each
+    /// one of `0`, `90`, `180`, or `270`, which are the only rotations that
+    /// Minecraft supports.
+    ///
+    /// Returns the first invalid rotation as the error.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::schemas::blockstates::*;
+    /// let props: ModelProperties =
+    ///     serde_json::from_str(r#"{ "model": "block/stone", "x": 90, "y": 45 }"#).unwrap();
+    ///
+    /// assert_eq!(props.validate(), Err(45));
+    /// ```
+    pub fn validate(&self) -> Result<(), i32> {
+        for rotation in [self.x, self.y] {
+            if !matches!(rotation, 0 | 90 | 180 | 270) {
+                return Err(rotation);
+            }
+        }
+
+        Ok(())
+    }
 }
 
 impl Default for ModelProperties {
@@ -251,6 +634,8 @@ impl Default for ModelProperties {
 
 /// Types used to compose [`BlockStates::Multipart`].
 pub mod multipart {
+    use std::cmp::Ordering;
+
     use super::*;
 
     /// Specifies a case and the model that should apply in that case.
@@ -283,6 +668,15 @@ pub mod multipart {
     }
 
     /// A list of conditions that have to be met for a model to be applied.
+    ///
+    /// Clauses do not nest: an `OR` clause only ever contains plain
+    /// [`Condition`]s, so [`applies()`][Self::applies] never recurses, and
+    /// input that nests one clause inside of another is rejected as invalid.
+    ///
+    /// Deserializing arbitrarily deep input is only as safe as the
+    /// deserializer being used, since that input must be read before it can
+    /// be rejected. Untrusted packs should be parsed with a deserializer that
+    /// limits its recursion depth.
     #[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
     #[serde(untagged)]
     pub enum WhenClause {
@@ -321,6 +715,75 @@ pub mod multipart {
                 .iter()
                 .any(|condition| condition.applies(state_values.clone()))
         }
+
+        /// Evaluates each of the conditions of this `when` clause against the
+        /// provided state values, reporting which requirements are not met.
+        ///
+        /// The clause [applies][WhenEvaluation::applies] under the same
+        /// circumstances as [`applies()`][Self::applies].
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// # use minecraft_assets::schemas::blockstates::multipart::*;
+        /// let clause: WhenClause = serde_json::from_str(
+        ///     r#"{ "OR": [{ "north": "side", "east": "side" }, { "up": true }] }"#,
+        /// )
+        /// .unwrap();
+        ///
+        /// let side = StateValue::from("side");
+        /// let no = StateValue::from(false);
+        /// let state_values = vec![("north", &side), ("east", &no), ("up", &no)];
+        ///
+        /// let evaluation = clause.evaluate(state_values);
+        /// assert!(!evaluation.applies());
+        /// assert_eq!(
+        ///     evaluation.conditions[0],
+        ///     ConditionEvaluation::Failed { unsatisfied: vec![String::from("east")] }
+        /// );
+        /// ```
+        pub fn evaluate<'a, I>(&self, state_values: I) -> WhenEvaluation
+        where
+            I: IntoIterator<Item = (&'a str, &'a StateValue)> + Clone,
+        {
+            WhenEvaluation {
+                conditions: self
+                    .conditions()
+                    .iter()
+                    .map(|condition| condition.evaluate(state_values.clone()))
+                    .collect(),
+            }
+        }
+    }
+
+    /// The result of [`WhenClause::evaluate()`].
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct WhenEvaluation {
+        /// The result of each condition of the clause, in the same order as
+        /// [`WhenClause::conditions()`].
+        pub conditions: Vec<ConditionEvaluation>,
+    }
+
+    impl WhenEvaluation {
+        /// Returns `true` if any of the conditions matched.
+        pub fn applies(&self) -> bool {
+            self.conditions.contains(&ConditionEvaluation::Matched)
+        }
+    }
+
+    /// The result of evaluating a single [`Condition`].
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub enum ConditionEvaluation {
+        /// Every requirement of the condition was met.
+        Matched,
+
+        /// Some requirements of the condition were not met.
+        Failed {
+            /// The names of the states whose requirements were not met, in
+            /// sorted order. This includes states that were missing from the
+            /// provided state values.
+            unsatisfied: Vec<String>,
+        },
     }
 
     /// A set of conditions that **all** have to match the block to return true.
@@ -389,6 +852,79 @@ pub mod multipart {
                     .unwrap_or(false)
             })
         }
+
+        /// Evaluates the condition against the provided state values, following
+        /// the same rules as [`applies()`][Self::applies].
+        pub fn evaluate<'a, I>(&self, state_values: I) -> ConditionEvaluation
+        where
+            I: IntoIterator<Item = (&'a str, &'a StateValue)>,
+        {
+            let state_values: HashMap<&'a str, &'a StateValue> = state_values.into_iter().collect();
+
+            let mut unsatisfied: Vec<String> = self
+                .and
+                .iter()
+                .filter(|(state, required_value)| {
+                    !state_values
+                        .get(state.as_str())
+                        .is_some_and(|value| **required_value == **value)
+                })
+                .map(|(state, _)| state.clone())
+                .collect();
+
+            if unsatisfied.is_empty() {
+                ConditionEvaluation::Matched
+            } else {
+                unsatisfied.sort();
+                ConditionEvaluation::Failed { unsatisfied }
+            }
+        }
+
+        /// Returns `true` if every requirement of the condition is met by the
+        /// given block state, which maps each property name to its value
+        /// (e.g., `"north" => "side"`).
+        ///
+        /// A requirement is met if the value of the property is one of the
+        /// [alternatives] of the required value. A property that is missing
+        /// from `state` never meets a requirement.
+        ///
+        /// [alternatives]: StateValue::alternatives
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// # use minecraft_assets::schemas::blockstates::multipart::*;
+        /// use maplit::hashmap;
+        ///
+        /// let condition: Condition =
+        ///     serde_json::from_str(r#"{ "north": "side|up", "east": false }"#).unwrap();
+        ///
+        /// let state = hashmap! {
+        ///     String::from("north") => String::from("up"),
+        ///     String::from("east") => String::from("false"),
+        /// };
+        /// assert!(condition.matches(&state));
+        ///
+        /// let state = hashmap! {
+        ///     String::from("north") => String::from("none"),
+        ///     String::from("east") => String::from("false"),
+        /// };
+        /// assert!(!condition.matches(&state));
+        ///
+        /// let state = hashmap! {
+        ///     String::from("north") => String::from("side"),
+        /// };
+        /// assert!(!condition.matches(&state));
+        /// ```
+        pub fn matches(&self, state: &HashMap<String, String>) -> bool {
+            self.and.iter().all(|(property, required_value)| {
+                state.get(property).is_some_and(|value| {
+                    required_value
+                        .alternatives()
+                        .any(|alternative| alternative == value)
+                })
+            })
+        }
     }
 
     /// The right-hand side of a [`Condition`] requirement.
@@ -433,8 +969,76 @@ pub mod multipart {
         pub fn as_bool(&self) -> Option<bool> {
             match self {
                 Self::Bool(b) => Some(*b),
-                Self::String(s) if s == "true" => Some(true),
-                Self::String(s) if s == "false" => Some(false),
+                Self::String(s) => Self::parse_bool(s),
+            }
+        }
+
+        /// Returns an iterator over the alternatives of this value.
+        ///
+        /// A string value like `"side|up"` means "`side` or `up`", and yields
+        /// each of the values separated by `|`. Any other string value or bool
+        /// value yields a single item.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// # use minecraft_assets::schemas::blockstates::multipart::*;
+        /// let value = StateValue::from("side|up");
+        /// assert_eq!(value.alternatives().collect::<Vec<_>>(), ["side", "up"]);
+        ///
+        /// let value = StateValue::from("side");
+        /// assert_eq!(value.alternatives().collect::<Vec<_>>(), ["side"]);
+        ///
+        /// let value = StateValue::from(false);
+        /// assert_eq!(value.alternatives().collect::<Vec<_>>(), ["false"]);
+        /// ```
+        pub fn alternatives(&self) -> impl Iterator<Item = &str> {
+            let s = match self {
+                Self::Bool(true) => "true",
+                Self::Bool(false) => "false",
+                Self::String(s) => s.as_str(),
+            };
+
+            s.split('|')
+        }
+
+        /// Compares the value to the given value of a numeric property (e.g.,
+        /// `level` or `age`), or returns `None` if either of them is not a
+        /// single integer.
+        ///
+        /// Block state values are stored as strings, so this parses both sides
+        /// before comparing them. Bool values and values with alternatives
+        /// (e.g., `"1|2"`) are never numeric.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// # use minecraft_assets::schemas::blockstates::multipart::*;
+        /// use std::cmp::Ordering;
+        ///
+        /// let value = StateValue::from("5");
+        /// assert_eq!(value.compare_numeric("3"), Some(Ordering::Greater));
+        /// assert_eq!(value.compare_numeric("15"), Some(Ordering::Less));
+        /// assert_eq!(value.compare_numeric("5"), Some(Ordering::Equal));
+        /// assert_eq!(value.compare_numeric("north"), None);
+        ///
+        /// assert_eq!(StateValue::from("1|2").compare_numeric("1"), None);
+        /// assert_eq!(StateValue::from(true).compare_numeric("1"), None);
+        /// ```
+        pub fn compare_numeric(&self, other: &str) -> Option<Ordering> {
+            let value = match self {
+                Self::Bool(_) => return None,
+                Self::String(s) => s.parse::<i64>().ok()?,
+            };
+            let other = other.parse::<i64>().ok()?;
+
+            Some(value.cmp(&other))
+        }
+
+        fn parse_bool(s: &str) -> Option<bool> {
+            match s {
+                "true" => Some(true),
+                "false" => Some(false),
                 _ => None,
             }
         }
@@ -510,32 +1114,64 @@ pub mod multipart {
     /// ```
     impl PartialEq for StateValue {
         fn eq(&self, other: &Self) -> bool {
+            match other {
+                Self::Bool(other_b) => self == other_b,
+                Self::String(other_s) => self == other_s.as_str(),
+            }
+        }
+    }
+
+    /// Compares the value to an unquoted boolean value, treating quoted
+    /// booleans (`"true"` and `"false"`) the same as unquoted ones.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::schemas::blockstates::multipart::*;
+    /// assert!(StateValue::from(true) == true);
+    /// assert!(StateValue::from("true") == true);
+    /// assert!(StateValue::from("false") != true);
+    /// assert!(StateValue::from("foo") != false);
+    /// ```
+    impl PartialEq<bool> for StateValue {
+        fn eq(&self, other: &bool) -> bool {
+            self.as_bool() == Some(*other)
+        }
+    }
+
+    /// Compares the value to a string, following the same rules as comparing
+    /// two [`StateValue`]s.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::schemas::blockstates::multipart::*;
+    /// assert!(StateValue::from(true) == "true");
+    /// assert!(StateValue::from("side|up") == "up");
+    /// assert!(StateValue::from("side") == "side|up");
+    /// assert!(StateValue::from("side") != "none");
+    /// ```
+    impl PartialEq<str> for StateValue {
+        fn eq(&self, other: &str) -> bool {
             match self {
+                Self::Bool(b) => Self::parse_bool(other) == Some(*b),
                 Self::String(s) => {
-                    match other {
-                        Self::Bool(other_b) => {
-                            self.as_bool().map(|b| b == *other_b).unwrap_or(false)
-                        }
-                        Self::String(other_s) => {
-                            s == other_s
-                                // Account for "or"s in this value (i.e., `|`).
-                                || s.split('|').any(|s| s == other_s)
-                                // Account for "or"s in the other value.
-                                || other_s.split('|').any(|other_s| s == other_s)
-                        }
-                    }
-                }
-                Self::Bool(b) => {
-                    if let Some(other_b) = other.as_bool() {
-                        *b == other_b
-                    } else {
-                        false
-                    }
+                    s == other
+                        // Account for "or"s in this value (i.e., `|`).
+                        || self.alternatives().any(|s| s == other)
+                        // Account for "or"s in the other value.
+                        || other.split('|').any(|other| s == other)
                 }
             }
         }
     }
 
+    impl<'a> PartialEq<&'a str> for StateValue {
+        fn eq(&self, other: &&'a str) -> bool {
+            self == *other
+        }
+    }
+
     impl From<bool> for StateValue {
         fn from(source: bool) -> Self {
             Self::Bool(source)
@@ -560,7 +1196,7 @@ mod test {
     use super::multipart::*;
     use super::*;
 
-    use maplit::hashmap;
+    use maplit::{btreemap, hashmap};
 
     fn make_single_variant(model_name: &str) -> Variant {
         Variant::Single(ModelProperties {
@@ -595,7 +1231,7 @@ mod test {
     #[test]
     fn test_single_variant() {
         let blockstates = BlockStates::Variants {
-            variants: hashmap! {
+            variants: btreemap! {
                 String::from("") => make_single_variant("model1"),
             },
         };
@@ -608,7 +1244,7 @@ mod test {
     #[test]
     fn test_variants() {
         let blockstates = BlockStates::Variants {
-            variants: hashmap! {
+            variants: btreemap! {
                 String::from("var1=foo,var2=true") => make_single_variant("model1"),
                 String::from("var1=foo,var2=false") => make_single_variant("model2"),
             },
@@ -622,6 +1258,98 @@ mod test {
         do_test(blockstates, &state_values, &["model2"]);
     }
 
+    #[test]
+    fn test_variant_key_round_trip() {
+        for key in [
+            "",
+            "axis=y",
+            "facing=east,half=bottom,shape=straight",
+            "east=side,north=none,power=15,south=up,west=none",
+        ] {
+            assert_eq!(BlockStates::parse_variant_key(key).to_key(), key);
+        }
+
+        let properties = BlockStates::parse_variant_key("half=bottom,facing=east");
+        assert_eq!(
+            properties.iter().collect::<Vec<_>>(),
+            vec![
+                (&String::from("facing"), &String::from("east")),
+                (&String::from("half"), &String::from("bottom")),
+            ]
+        );
+        assert_eq!(properties.to_key(), "facing=east,half=bottom");
+
+        let properties: BlockStateProperties = [("powered", "false"), ("face", "wall")]
+            .into_iter()
+            .collect();
+        assert_eq!(properties.to_key(), "face=wall,powered=false");
+    }
+
+    #[test]
+    fn test_active_models_variants() {
+        let blockstates = BlockStates::Variants {
+            variants: btreemap! {
+                String::from("facing=east,half=top") => make_single_variant("model1"),
+                String::from("facing=east,half=bottom") => make_single_variant("model2"),
+                String::from("facing=west,half=bottom") => make_single_variant("model3"),
+            },
+        };
+
+        let state = hashmap! {
+            String::from("facing") => String::from("east"),
+            String::from("half") => String::from("bottom"),
+            String::from("waterlogged") => String::from("false"),
+        };
+
+        let models: Vec<&str> = blockstates
+            .active_models(&state)
+            .into_iter()
+            .map(|model| model.model.as_str())
+            .collect();
+        assert_eq!(models, vec!["model2"]);
+
+        let state = hashmap! {
+            String::from("facing") => String::from("east"),
+        };
+        assert!(blockstates.active_models(&state).is_empty());
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_choose_weighted() {
+        use rand::{rngs::StdRng, SeedableRng};
+
+        let mut rng = StdRng::seed_from_u64(0);
+
+        let variant = make_single_variant("model1");
+        for _ in 0..10 {
+            assert_eq!(variant.choose_weighted(&mut rng).model, "model1");
+        }
+
+        let make_weighted = |model: &str, weight| ModelProperties {
+            model: String::from(model),
+            weight,
+            ..Default::default()
+        };
+        let variant = Variant::Multiple(vec![
+            make_weighted("never", 0),
+            make_weighted("rare", 1),
+            make_weighted("common", 3),
+        ]);
+
+        let mut counts: HashMap<&str, u32> = HashMap::new();
+        for _ in 0..4000 {
+            let model = &variant.choose_weighted(&mut rng).model;
+            *counts.entry(model.as_str()).or_default() += 1;
+        }
+
+        assert_eq!(counts.get("never"), None);
+        let rare = counts["rare"];
+        let common = counts["common"];
+        assert_eq!(rare + common, 4000);
+        assert!((800..1200).contains(&rare), "rare chosen {} times", rare);
+    }
+
     #[test]
     fn test_multipart() {
         let blockstates = BlockStates::Multipart {
@@ -649,4 +1377,107 @@ mod test {
 
         do_test(blockstates, &state_values, &["model1", "model2"]);
     }
+
+    #[test]
+    fn test_nested_when_clause() {
+        let json = r#"{
+            "when": { "OR": [{ "OR": [{ "facing": "north" }] }] },
+            "apply": { "model": "a" }
+        }"#;
+
+        assert!(serde_json::from_str::<Case>(json).is_err());
+    }
+
+    #[test]
+    fn test_model_properties_defaults() {
+        let json = r#"{ "model": "block/stone" }"#;
+        let model: ModelProperties = serde_json::from_str(json).unwrap();
+
+        assert_eq!(
+            model,
+            ModelProperties {
+                model: String::from("block/stone"),
+                x: 0,
+                y: 0,
+                uv_lock: false,
+                weight: 1,
+            }
+        );
+        assert_eq!(
+            model,
+            ModelProperties {
+                model: String::from("block/stone"),
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn test_variants_serialize_sorted() {
+        let json = r#"{
+            "variants": {
+                "powered=true": { "model": "block/stone_pressure_plate_down" },
+                "powered=false": { "model": "block/stone_pressure_plate" }
+            }
+        }"#;
+
+        let blockstates: BlockStates = serde_json::from_str(json).unwrap();
+        let serialized = serde_json::to_string(&blockstates).unwrap();
+
+        let false_index = serialized.find("powered=false").unwrap();
+        let true_index = serialized.find("powered=true").unwrap();
+        assert!(false_index < true_index);
+
+        // Serializing again yields exactly the same output.
+        let reparsed: BlockStates = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(serde_json::to_string(&reparsed).unwrap(), serialized);
+    }
+
+    #[test]
+    fn test_when_clause_evaluate() {
+        let clause = WhenClause::Single(Condition {
+            and: hashmap! {
+                String::from("north") => StateValue::from("side|up"),
+                String::from("east") => StateValue::from("side"),
+                String::from("waterlogged") => StateValue::from(false),
+            },
+        });
+
+        let up = StateValue::from("up");
+        let none = StateValue::from("none");
+        let no = StateValue::from("false");
+
+        let state_values = [("north", &up), ("east", &none), ("waterlogged", &no)];
+        let evaluation = clause.evaluate(state_values.iter().copied());
+        assert_eq!(
+            evaluation.conditions,
+            [ConditionEvaluation::Failed {
+                unsatisfied: vec![String::from("east")]
+            }]
+        );
+        assert!(!evaluation.applies());
+        assert_eq!(
+            evaluation.applies(),
+            clause.applies(state_values.iter().copied())
+        );
+
+        // Missing states are unsatisfied too.
+        let state_values = vec![("north", &none)];
+        assert_eq!(
+            clause.evaluate(state_values).conditions,
+            [ConditionEvaluation::Failed {
+                unsatisfied: vec![
+                    String::from("east"),
+                    String::from("north"),
+                    String::from("waterlogged")
+                ]
+            }]
+        );
+
+        let side = StateValue::from("side");
+        let state_values = vec![("north", &up), ("east", &side), ("waterlogged", &no)];
+        let evaluation = clause.evaluate(state_values);
+        assert_eq!(evaluation.conditions, [ConditionEvaluation::Matched]);
+        assert!(evaluation.applies());
+    }
 }
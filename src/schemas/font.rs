@@ -0,0 +1,203 @@
+//! Serde-(de)serializable data types for `assets/<namespace>/font/*.json`.
+//!
+//! Start here: [`FontDefinition`].
+//!
+//! See <https://minecraft.fandom.com/wiki/Resource_pack#Fonts>.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+/// A font as stored in the `assets/<namespace>/font/` directory.
+///
+/// A font is made up of a list of [`FontProvider`]s. When rendering a
+/// character, the providers are checked in order, and the first one that
+/// supplies a glyph for the character is used.
+///
+/// See also the corresponding section of the [wiki page].
+///
+/// [wiki page]: <https://minecraft.fandom.com/wiki/Resource_pack#Fonts>
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct FontDefinition {
+    /// The providers that make up the font, in order of priority.
+    pub providers: Vec<FontProvider>,
+}
+
+impl FontDefinition {
+    /// Returns the first provider that supplies a glyph for the given
+    /// character, or `None` if no provider does.
+    ///
+    /// See [`FontProvider::provides()`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::schemas::font::*;
+    /// let json = r#"{
+    ///     "providers": [
+    ///         { "type": "space", "advances": { " ": 4 } },
+    ///         {
+    ///             "type": "bitmap",
+    ///             "file": "minecraft:font/ascii.png",
+    ///             "ascent": 7,
+    ///             "chars": ["ABC", "abc"]
+    ///         }
+    ///     ]
+    /// }"#;
+    ///
+    /// let font: FontDefinition = serde_json::from_str(json).unwrap();
+    ///
+    /// assert_eq!(font.provider_for_char(' '), Some(&font.providers[0]));
+    /// assert_eq!(font.provider_for_char('b'), Some(&font.providers[1]));
+    /// assert_eq!(font.provider_for_char('z'), None);
+    /// ```
+    pub fn provider_for_char(&self, c: char) -> Option<&FontProvider> {
+        self.providers.iter().find(|provider| provider.provides(c))
+    }
+}
+
+/// A source of glyphs in a [`FontDefinition`].
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum FontProvider {
+    /// Glyphs taken from a grid of characters in a texture.
+    Bitmap {
+        /// The [resource location] of the texture, relative to `textures/`,
+        /// including the `.png` extension.
+        ///
+        /// [resource location]: <https://minecraft.fandom.com/wiki/Model#File_path>
+        file: String,
+
+        /// The height that each character is rendered at (`8` - default).
+        #[serde(default = "FontProvider::default_height")]
+        height: i32,
+
+        /// The vertical shift of the characters from the baseline.
+        ascent: i32,
+
+        /// The rows of characters in the texture, from top to bottom. The
+        /// texture is split into a grid with as many cells as there are
+        /// characters in each row.
+        ///
+        /// A `'\0'` character marks an unused cell.
+        chars: Vec<String>,
+    },
+
+    /// Glyphs rendered from a TrueType or OpenType font file.
+    Ttf {
+        /// The [resource location] of the font file, relative to `font/`,
+        /// including the extension.
+        ///
+        /// [resource location]: <https://minecraft.fandom.com/wiki/Model#File_path>
+        file: String,
+
+        /// The horizontal and vertical shift of the characters.
+        #[serde(default)]
+        shift: [f32; 2],
+
+        /// The size to render the characters at (`11.0` - default).
+        #[serde(default = "FontProvider::default_ttf_size")]
+        size: f32,
+
+        /// The resolution to render the characters at (`1.0` - default).
+        #[serde(default = "FontProvider::default_oversample")]
+        oversample: f32,
+
+        /// Characters that this provider should not supply.
+        #[serde(default)]
+        skip: SkipChars,
+    },
+
+    /// Glyphs taken from the pre-1.13 unicode font textures, one texture per
+    /// 256 code points.
+    LegacyUnicode {
+        /// The [resource location] of the binary file that contains the
+        /// width of each glyph.
+        ///
+        /// [resource location]: <https://minecraft.fandom.com/wiki/Model#File_path>
+        sizes: String,
+
+        /// The [resource location] of the textures, with `%s` standing in for
+        /// the high byte of each code point in hex (e.g.,
+        /// `"minecraft:font/unicode_page_%s.png"`).
+        ///
+        /// [resource location]: <https://minecraft.fandom.com/wiki/Model#File_path>
+        template: String,
+    },
+
+    /// Invisible glyphs that only advance the cursor.
+    Space {
+        /// Maps each character to the number of pixels it advances the cursor
+        /// by.
+        advances: HashMap<char, f32>,
+    },
+
+    /// Any other type of provider that is not yet supported by this crate.
+    ///
+    /// This variant cannot be serialized.
+    #[serde(other, skip_serializing)]
+    Other,
+}
+
+impl FontProvider {
+    pub(crate) const fn default_height() -> i32 {
+        8
+    }
+
+    pub(crate) const fn default_ttf_size() -> f32 {
+        11.0
+    }
+
+    pub(crate) const fn default_oversample() -> f32 {
+        1.0
+    }
+
+    /// Returns `true` if this provider supplies a glyph for the given
+    /// character.
+    ///
+    /// * A [`Bitmap`][Self::Bitmap] provider supplies the characters listed
+    ///   in its `chars`.
+    /// * A [`Space`][Self::Space] provider supplies the characters listed in
+    ///   its `advances`.
+    /// * A [`LegacyUnicode`][Self::LegacyUnicode] provider supplies every
+    ///   character in the Basic Multilingual Plane (`U+0000` to `U+FFFF`).
+    /// * A [`Ttf`][Self::Ttf] provider is assumed to supply every character
+    ///   that it does not `skip`, since the font file itself is not inspected.
+    /// * An [`Other`][Self::Other] provider never supplies any characters.
+    pub fn provides(&self, c: char) -> bool {
+        match self {
+            Self::Bitmap { chars, .. } => c != '\0' && chars.iter().any(|row| row.contains(c)),
+            Self::Ttf { skip, .. } => !skip.contains(c),
+            Self::LegacyUnicode { .. } => (c as u32) <= 0xFFFF,
+            Self::Space { advances } => advances.contains_key(&c),
+            Self::Other => false,
+        }
+    }
+}
+
+/// The characters skipped by a [`Ttf`][FontProvider::Ttf] provider.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[serde(untagged)]
+pub enum SkipChars {
+    /// All of the characters in a single string.
+    Single(String),
+
+    /// All of the characters in a list of strings.
+    Multiple(Vec<String>),
+}
+
+impl Default for SkipChars {
+    fn default() -> Self {
+        Self::Multiple(Vec::new())
+    }
+}
+
+impl SkipChars {
+    /// Returns `true` if the given character is skipped.
+    pub fn contains(&self, c: char) -> bool {
+        match self {
+            Self::Single(chars) => chars.contains(c),
+            Self::Multiple(rows) => rows.iter().any(|row| row.contains(c)),
+        }
+    }
+}
@@ -0,0 +1,151 @@
+//! Serde-(de)serializable data types for
+//! `assets/<namespace>/textures/gui/sprites/**/*.png.mcmeta`.
+//!
+//! Start here: [`GuiSpriteMeta`].
+//!
+//! Since 1.20.2, the textures of the GUI are split into individual sprites
+//! that are stitched into the `gui` atlas. The `.mcmeta` file next to a sprite
+//! describes how the sprite is scaled when it is drawn at a different size
+//! than the texture, e.g., to draw buttons of any width.
+//!
+//! See <https://minecraft.fandom.com/wiki/Resource_pack#GUI>.
+
+use serde::{Deserialize, Serialize};
+
+/// The metadata of a GUI sprite, as stored in the `.png.mcmeta` file next to
+/// the sprite's texture.
+///
+/// # Example
+///
+/// ```
+/// # use minecraft_assets::schemas::gui_sprite::*;
+/// let json = r#"{
+///     "gui": {
+///         "scaling": {
+///             "type": "nine_slice",
+///             "width": 200,
+///             "height": 20,
+///             "border": { "left": 20, "top": 4, "right": 20, "bottom": 4 }
+///         }
+///     }
+/// }"#;
+///
+/// let meta: GuiSpriteMeta = serde_json::from_str(json).unwrap();
+///
+/// match meta.gui.scaling {
+///     GuiScaling::NineSlice { width, height, border } => {
+///         assert_eq!((width, height), (200, 20));
+///         assert_eq!(
+///             border.sides(),
+///             BorderSides { left: 20, top: 4, right: 20, bottom: 4 }
+///         );
+///     }
+///     other => panic!("unexpected scaling: {:?}", other),
+/// }
+/// ```
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq, Eq)]
+pub struct GuiSpriteMeta {
+    /// How the sprite is drawn in the GUI.
+    ///
+    /// Sprites whose `.mcmeta` file has no `gui` section (e.g., one that only
+    /// describes an animation) are [stretched][GuiScaling::Stretch].
+    #[serde(default)]
+    pub gui: GuiMetadata,
+}
+
+/// The `gui` section of a [`GuiSpriteMeta`].
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq, Eq)]
+pub struct GuiMetadata {
+    /// How the sprite is scaled to the size that it is drawn at
+    /// ([`Stretch`][GuiScaling::Stretch] - default).
+    #[serde(default)]
+    pub scaling: GuiScaling,
+}
+
+/// How a GUI sprite is scaled when it is drawn at a different size than its
+/// texture.
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq, Eq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum GuiScaling {
+    /// The whole texture is stretched to the drawn size.
+    #[default]
+    Stretch,
+
+    /// The texture is repeated to fill the drawn size.
+    Tile {
+        /// The width, in pixels, that the texture is treated as having.
+        width: u32,
+
+        /// The height, in pixels, that the texture is treated as having.
+        height: u32,
+    },
+
+    /// The texture is split into a 3x3 grid: the corners are drawn as-is,
+    /// the edges are repeated along one axis, and the center is repeated
+    /// along both axes.
+    NineSlice {
+        /// The width, in pixels, that the texture is treated as having.
+        width: u32,
+
+        /// The height, in pixels, that the texture is treated as having.
+        height: u32,
+
+        /// The size, in pixels, of the edges of the grid.
+        border: NineSliceBorder,
+    },
+}
+
+/// The size of the edges of a [`NineSlice`][GuiScaling::NineSlice] sprite.
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(untagged)]
+pub enum NineSliceBorder {
+    /// The same size for every edge.
+    Uniform(u32),
+
+    /// A different size for each edge.
+    Sides(BorderSides),
+}
+
+impl NineSliceBorder {
+    /// Returns the size of each edge.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::schemas::gui_sprite::*;
+    /// let border = NineSliceBorder::Uniform(3);
+    ///
+    /// assert_eq!(
+    ///     border.sides(),
+    ///     BorderSides { left: 3, top: 3, right: 3, bottom: 3 }
+    /// );
+    /// ```
+    pub fn sides(&self) -> BorderSides {
+        match *self {
+            Self::Uniform(size) => BorderSides {
+                left: size,
+                top: size,
+                right: size,
+                bottom: size,
+            },
+            Self::Sides(sides) => sides,
+        }
+    }
+}
+
+/// The size, in pixels, of each edge of a
+/// [`NineSlice`][GuiScaling::NineSlice] sprite.
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BorderSides {
+    /// The size of the left edge.
+    pub left: u32,
+
+    /// The size of the top edge.
+    pub top: u32,
+
+    /// The size of the right edge.
+    pub right: u32,
+
+    /// The size of the bottom edge.
+    pub bottom: u32,
+}
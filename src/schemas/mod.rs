@@ -1,8 +1,21 @@
-//! Serde-(de)serializable data types for files in the Minecraft `assets/`
-//! directory.
+//! Serde-(de)serializable data types for files in the Minecraft `assets/` and
+//! `data/` directories.
 
+pub mod atlas;
 pub mod blockstates;
+pub mod font;
+pub mod gui_sprite;
 pub mod models;
+pub mod optifine;
+pub mod particles;
+pub mod recipes;
+pub mod tags;
 
+pub use atlas::AtlasDefinition;
 pub use blockstates::BlockStates;
+pub use font::FontDefinition;
+pub use gui_sprite::GuiSpriteMeta;
 pub use models::Model;
+pub use particles::ParticleDefinition;
+pub use recipes::Recipe;
+pub use tags::Tag;
@@ -6,12 +6,14 @@
 //! See <https://minecraft.fandom.com/wiki/Model#Block_models>.
 
 use std::{
-    collections::HashMap,
+    collections::{hash_map, BTreeSet, HashMap, HashSet},
     hash::Hash,
     ops::{Deref, DerefMut},
 };
 
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
+
+use crate::api::{ModelIdentifier, ResourceIdentifier, ResourceKind};
 
 /// A block or item model as stored in the
 /// `assets/<namespace>/models/{block,item}/` directories.
@@ -19,6 +21,23 @@ use serde::{Deserialize, Serialize};
 /// See also the corresponding section of the [wiki page]
 ///
 /// [wiki page]: <https://minecraft.fandom.com/wiki/Model#Block_models>
+///
+/// # Serialization
+///
+/// Fields that are `None` are left out when serializing, so that the output
+/// looks like a hand-written model file. Fields that are present but empty
+/// (e.g., `"elements": []`) are kept, since they override the parent's value.
+///
+/// ```
+/// # use minecraft_assets::schemas::models::*;
+/// let model = Model {
+///     parent: Some(String::from("block/cube")),
+///     ..Default::default()
+/// };
+///
+/// let json = serde_json::to_string(&model).unwrap();
+/// assert_eq!(json, r#"{"parent":"block/cube"}"#);
+/// ```
 #[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq)]
 pub struct Model {
     /// Specifies that this model should inherit fields from the model at the
@@ -36,40 +55,56 @@ pub struct Model {
     ///     tridents).
     ///
     /// [resource location]: <https://minecraft.fandom.com/wiki/Model#File_path>
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub parent: Option<String>,
 
     /// Contains the different places where item models are displayed in
     /// different views.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub display: Option<Display>,
 
     /// Contains the textures of the model.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub textures: Option<Textures>,
 
     /// Contains all the elements of the model.
     ///
     /// If both `parent` and `elements` are set, the `elements` tag overrides
     /// the `elements` tag from the previous model.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub elements: Option<Vec<Element>>,
 
     /// Whether to use ambient occlusion (`true` - default), or not (`false`).
+    /// See [`effective_ambient_occlusion()`][Self::effective_ambient_occlusion].
     ///
     /// **Applies only to block models.**
-    #[serde(rename = "ambientocclusion")]
+    #[serde(rename = "ambientocclusion", skip_serializing_if = "Option::is_none")]
     pub ambient_occlusion: Option<bool>,
 
     /// Specifies how to shade the model in the GUI.
     ///
     /// Can be `front` or `side`. If set to `side`, the model is rendered like a
     /// block. If set to `front`, model is shaded like a flat item. Defaults to
-    /// `side`.
+    /// `front` for item models and `side` for block models (see
+    /// [`effective_gui_light()`][Self::effective_gui_light]).
     ///
     /// **Applies only to item models.**[^1]
     ///
     /// [^1]: In versions >= 1.16.2, it appears that `block/block.json` also has
     ///     this field set.
-    #[serde(rename = "gui_light")]
+    #[serde(rename = "gui_light", skip_serializing_if = "Option::is_none")]
     pub gui_light_mode: Option<GuiLightMode>,
 
+    /// The render layer that the model is drawn in, e.g., `"solid"`,
+    /// `"cutout"`, or `"translucent"`.
+    ///
+    /// This is kept as a string since the set of values differs between
+    /// versions and mod loaders.
+    ///
+    /// **Present in versions >= 1.19.**
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub render_type: Option<String>,
+
     /// Specifies cases in which a different model should be used based on item
     /// tags.
     ///
@@ -79,7 +114,611 @@ pub struct Model {
     /// to the same model.
     ///
     /// **Applies only to item models.**
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub overrides: Option<Vec<OverrideCase>>,
+
+    /// Any other fields of the model that are not otherwise recognized.
+    ///
+    /// Model editors add fields such as `"credit"` or `"__comment"` to the
+    /// models they produce. These are kept here so that a model can be loaded,
+    /// edited, and saved without losing them. See also
+    /// [`eq_semantic()`][Self::eq_semantic].
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+impl Model {
+    /// Returns `true` if `self` and `other` are equal, ignoring any fields
+    /// that are not otherwise recognized (see [`extra`][Self::extra] and
+    /// [`Element::extra`]).
+    ///
+    /// This is useful for comparing models that only differ in metadata added
+    /// by model editors.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::schemas::models::*;
+    /// let plain: Model = serde_json::from_str(r#"{
+    ///     "parent": "block/cube_all"
+    /// }"#).unwrap();
+    ///
+    /// let credited: Model = serde_json::from_str(r#"{
+    ///     "credit": "Made with Blockbench",
+    ///     "parent": "block/cube_all"
+    /// }"#).unwrap();
+    ///
+    /// assert!(plain.eq_semantic(&credited));
+    /// assert_ne!(plain, credited);
+    /// ```
+    pub fn eq_semantic(&self, other: &Self) -> bool {
+        let elements_eq = match (&self.elements, &other.elements) {
+            (Some(a), Some(b)) => {
+                a.len() == b.len() && a.iter().zip(b).all(|(a, b)| a.eq_semantic(b))
+            }
+            (a, b) => a.is_none() && b.is_none(),
+        };
+
+        self.parent == other.parent
+            && self.display == other.display
+            && self.textures == other.textures
+            && elements_eq
+            && self.ambient_occlusion == other.ambient_occlusion
+            && self.gui_light_mode == other.gui_light_mode
+            && self.render_type == other.render_type
+            && self.overrides == other.overrides
+    }
+
+    /// Returns `true` if the [`parent`][Self::parent] of this model is a
+    /// builtin model with no corresponding file (e.g., `builtin/generated`).
+    ///
+    /// Returns `false` if the model has no parent. See
+    /// [`ModelIdentifier::is_builtin()`].
+    ///
+    /// [`ModelIdentifier::is_builtin()`]: crate::api::ModelIdentifier::is_builtin
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::schemas::models::*;
+    /// let model = Model {
+    ///     parent: Some(String::from("builtin/entity")),
+    ///     ..Default::default()
+    /// };
+    /// assert!(model.parent_is_builtin());
+    ///
+    /// let model = Model {
+    ///     parent: Some(String::from("item/generated")),
+    ///     ..Default::default()
+    /// };
+    /// assert!(!model.parent_is_builtin());
+    /// ```
+    pub fn parent_is_builtin(&self) -> bool {
+        self.parent
+            .as_deref()
+            .is_some_and(ModelIdentifier::is_builtin)
+    }
+
+    /// Returns the resources that this model refers to directly, without
+    /// looking at its parents.
+    ///
+    /// The first id is the model's [`parent`][Self::parent], if it has one that
+    /// is not builtin. The parent is of the same kind as this model, given by
+    /// `self_kind`. It is followed by each distinct texture that is assigned to
+    /// one of the model's [`textures`][Self::textures] or to a face of one of
+    /// its [`elements`][Self::elements], in sorted order. Texture variables
+    /// (e.g., `#all`) are skipped.
+    ///
+    /// All of the ids are in their [canonical form].
+    ///
+    /// [canonical form]: crate::api::ResourceIdentifier::to_canonical
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::schemas::models::*;
+    /// use minecraft_assets::api::{ResourceIdentifier, ResourceKind};
+    /// use maplit::hashmap;
+    ///
+    /// let model = Model {
+    ///     parent: Some(String::from("block/cube_column")),
+    ///     textures: Some(Textures::from(hashmap! {
+    ///         "end" => "block/oak_log_top",
+    ///         "side" => "block/oak_log",
+    ///         "particle" => "#side",
+    ///     })),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// assert_eq!(
+    ///     model.direct_references(ResourceKind::BlockModel),
+    ///     [
+    ///         ResourceIdentifier::block_model("minecraft:block/cube_column"),
+    ///         ResourceIdentifier::texture("minecraft:block/oak_log"),
+    ///         ResourceIdentifier::texture("minecraft:block/oak_log_top"),
+    ///     ]
+    /// );
+    /// ```
+    pub fn direct_references(&self, self_kind: ResourceKind) -> Vec<ResourceIdentifier<'static>> {
+        let parent = self
+            .parent
+            .as_deref()
+            .filter(|parent| !ModelIdentifier::is_builtin(parent))
+            .map(|parent| ResourceIdentifier::new(self_kind, parent));
+
+        let variables = self.textures.iter().flat_map(|textures| textures.values());
+        let faces = self
+            .elements
+            .iter()
+            .flatten()
+            .flat_map(|element| element.faces.values())
+            .map(|face| &face.texture);
+        let textures: BTreeSet<&str> = variables
+            .chain(faces)
+            .filter_map(Texture::location)
+            .collect();
+
+        parent
+            .into_iter()
+            .chain(textures.into_iter().map(ResourceIdentifier::texture))
+            .map(|id| id.to_canonical().to_owned())
+            .collect()
+    }
+
+    /// Returns the [`GuiLightMode`] that should be used to shade this model in
+    /// the GUI, applying the appropriate default if the `gui_light` field is
+    /// absent.
+    ///
+    /// Item models default to [`Front`] and block models default to [`Side`].
+    ///
+    /// Note that this only looks at `self`. To account for a value inherited
+    /// from a parent model, call this on the result of
+    /// [`ModelResolver::resolve_model()`].
+    ///
+    /// [`Front`]: GuiLightMode::Front
+    /// [`Side`]: GuiLightMode::Side
+    /// [`ModelResolver::resolve_model()`]: crate::api::ModelResolver::resolve_model
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::schemas::models::*;
+    /// let model = Model::default();
+    /// assert_eq!(model.effective_gui_light(true), GuiLightMode::Front);
+    /// assert_eq!(model.effective_gui_light(false), GuiLightMode::Side);
+    ///
+    /// let model = Model {
+    ///     gui_light_mode: Some(GuiLightMode::Side),
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(model.effective_gui_light(true), GuiLightMode::Side);
+    /// ```
+    pub fn effective_gui_light(&self, is_item: bool) -> GuiLightMode {
+        self.gui_light_mode.unwrap_or(if is_item {
+            GuiLightMode::Front
+        } else {
+            GuiLightMode::Side
+        })
+    }
+
+    /// Returns whether ambient occlusion should be used when rendering this
+    /// model, applying the default of `true` if the `ambientocclusion` field is
+    /// absent.
+    ///
+    /// Note that this only looks at `self`. To account for a value inherited
+    /// from a parent model, call this on the result of
+    /// [`ModelResolver::resolve_model()`].
+    ///
+    /// [`ModelResolver::resolve_model()`]: crate::api::ModelResolver::resolve_model
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::schemas::models::*;
+    /// let model = Model::default();
+    /// assert!(model.effective_ambient_occlusion());
+    ///
+    /// let model: Model = serde_json::from_str(r#"{ "ambientocclusion": false }"#).unwrap();
+    /// assert!(!model.effective_ambient_occlusion());
+    /// ```
+    pub fn effective_ambient_occlusion(&self) -> bool {
+        self.ambient_occlusion.unwrap_or(true)
+    }
+
+    /// Returns the location of the texture that particles are drawn with when
+    /// the block is broken or walked on, i.e., the value of the `particle`
+    /// texture variable.
+    ///
+    /// The `particle` texture is not used by any face, so a model may set it
+    /// even if it has no elements (e.g., the model of a block entity like a
+    /// chest). Returns `None` if the variable is not set, or if it still
+    /// references another variable.
+    ///
+    /// Note that this only looks at the `textures` of `self`. To account for
+    /// textures inherited from a parent model, call this on the result of
+    /// [`ModelResolver::resolve_model()`].
+    ///
+    /// [`ModelResolver::resolve_model()`]: crate::api::ModelResolver::resolve_model
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::schemas::models::*;
+    /// let model: Model = serde_json::from_str(r#"{
+    ///     "textures": { "particle": "block/oak_planks" }
+    /// }"#).unwrap();
+    /// assert_eq!(model.particle_texture(), Some("block/oak_planks"));
+    ///
+    /// let model: Model = serde_json::from_str(r##"{
+    ///     "textures": { "particle": "#all" }
+    /// }"##).unwrap();
+    /// assert_eq!(model.particle_texture(), None);
+    /// ```
+    pub fn particle_texture(&self) -> Option<&str> {
+        self.textures
+            .as_ref()?
+            .variables
+            .get("particle")?
+            .location()
+    }
+
+    /// Returns `true` if this model fully occupies its block, so that the
+    /// faces of neighboring blocks touching it can be culled.
+    ///
+    /// This is a heuristic approximation of vanilla occlusion: the model must
+    /// have exactly one [`Element`] that spans from `[0, 0, 0]` to
+    /// `[16, 16, 16]`, has all six faces, and is not rotated. Texture
+    /// transparency is not taken into account, so e.g. glass is considered a
+    /// full cube.
+    ///
+    /// Note that this only looks at the `elements` of `self`. To account for
+    /// elements inherited from a parent model, call this on the result of
+    /// [`ModelResolver::resolve_model()`].
+    ///
+    /// [`ModelResolver::resolve_model()`]: crate::api::ModelResolver::resolve_model
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::schemas::models::*;
+    /// let cube: Model = serde_json::from_str(r##"{
+    ///     "elements": [{
+    ///         "from": [0, 0, 0],
+    ///         "to": [16, 16, 16],
+    ///         "faces": {
+    ///             "down":  { "texture": "#down", "cullface": "down" },
+    ///             "up":    { "texture": "#up", "cullface": "up" },
+    ///             "north": { "texture": "#north", "cullface": "north" },
+    ///             "south": { "texture": "#south", "cullface": "south" },
+    ///             "west":  { "texture": "#west", "cullface": "west" },
+    ///             "east":  { "texture": "#east", "cullface": "east" }
+    ///         }
+    ///     }]
+    /// }"##).unwrap();
+    /// assert!(cube.is_full_cube());
+    ///
+    /// let mut slab = cube.clone();
+    /// slab.elements.as_mut().unwrap()[0].to = [16.0, 8.0, 16.0];
+    /// assert!(!slab.is_full_cube());
+    ///
+    /// assert!(!Model::default().is_full_cube());
+    /// ```
+    pub fn is_full_cube(&self) -> bool {
+        let element = match self.elements.as_deref() {
+            Some([element]) => element,
+            _ => return false,
+        };
+
+        element.from == [0.0, 0.0, 0.0]
+            && element.to == [16.0, 16.0, 16.0]
+            && element.rotation.angle == 0.0
+            && element.faces.len() == 6
+    }
+
+    /// Prepends `default_namespace` to every texture [location] in this model
+    /// that does not already have a namespace.
+    ///
+    /// This applies to the values of the [`textures`] map as well as the
+    /// textures of each element face. Texture variable [references] (values
+    /// starting with `#`) are left unchanged.
+    ///
+    /// [location]: Texture::location
+    /// [`textures`]: Self::textures
+    /// [references]: Texture::reference
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::schemas::models::*;
+    /// let mut model: Model = serde_json::from_str(r##"{
+    ///     "textures": {
+    ///         "all": "block/stone",
+    ///         "particle": "#all",
+    ///         "overlay": "foo:block/overlay"
+    ///     }
+    /// }"##).unwrap();
+    ///
+    /// model.normalize_texture_refs("minecraft");
+    ///
+    /// let textures = model.textures.as_ref().unwrap();
+    /// assert_eq!(textures["all"].0, "minecraft:block/stone");
+    /// assert_eq!(textures["particle"].0, "#all");
+    /// assert_eq!(textures["overlay"].0, "foo:block/overlay");
+    /// ```
+    pub fn normalize_texture_refs(&mut self, default_namespace: &str) {
+        let variables = self
+            .textures
+            .iter_mut()
+            .flat_map(|textures| textures.values_mut());
+
+        let faces = self
+            .elements
+            .iter_mut()
+            .flatten()
+            .flat_map(|element| element.faces.values_mut())
+            .map(|face| &mut face.texture);
+
+        for texture in variables.chain(faces) {
+            if texture
+                .location()
+                .is_some_and(|location| !location.contains(':'))
+            {
+                texture.0 = format!("{}:{}", default_namespace, texture.0);
+            }
+        }
+    }
+
+    /// Groups the faces of this model's elements that lie on the same plane,
+    /// face the same direction, and use the same texture.
+    ///
+    /// Each face is identified by the index of its element in
+    /// [`elements`][Self::elements] and its [`BlockFace`]. Every face appears
+    /// in exactly one group, so faces that cannot be merged with any other
+    /// face form a group of their own. This is intended as input to a greedy
+    /// mesher, which can then merge the faces of each group into larger quads.
+    ///
+    /// Groups are ordered by the first face they contain, and the faces of each
+    /// group are ordered by element index and then by direction.
+    ///
+    /// **Note:** element rotations are ignored, so the faces of a rotated
+    /// element are grouped as if the element were not rotated. UVs and tints
+    /// are also not compared.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::schemas::models::*;
+    /// let model: Model = serde_json::from_str(r##"{
+    ///     "elements": [
+    ///         {
+    ///             "from": [0, 0, 0],
+    ///             "to": [8, 16, 16],
+    ///             "faces": {
+    ///                 "up": { "texture": "#top" },
+    ///                 "north": { "texture": "#side" }
+    ///             }
+    ///         },
+    ///         {
+    ///             "from": [8, 0, 0],
+    ///             "to": [16, 16, 16],
+    ///             "faces": {
+    ///                 "up": { "texture": "#top" },
+    ///                 "north": { "texture": "#side" }
+    ///             }
+    ///         },
+    ///         {
+    ///             "from": [0, 16, 0],
+    ///             "to": [16, 17, 16],
+    ///             "faces": {
+    ///                 "up": { "texture": "#top" }
+    ///             }
+    ///         }
+    ///     ]
+    /// }"##).unwrap();
+    ///
+    /// assert_eq!(
+    ///     model.coplanar_face_groups(),
+    ///     vec![
+    ///         vec![(0, BlockFace::Up), (1, BlockFace::Up)],
+    ///         vec![(0, BlockFace::North), (1, BlockFace::North)],
+    ///         vec![(2, BlockFace::Up)],
+    ///     ]
+    /// );
+    /// ```
+    pub fn coplanar_face_groups(&self) -> Vec<Vec<(usize, BlockFace)>> {
+        let mut groups: Vec<Vec<(usize, BlockFace)>> = Vec::new();
+        let mut group_indices: HashMap<(BlockFace, u32, &str), usize> = HashMap::new();
+
+        for (index, element) in self.elements.iter().flatten().enumerate() {
+            let mut faces: Vec<_> = element.faces.iter().collect();
+            faces.sort_by_key(|(direction, _)| **direction as u8);
+
+            for (&direction, face) in faces {
+                let plane = match direction {
+                    BlockFace::Down => element.from[1],
+                    BlockFace::Up => element.to[1],
+                    BlockFace::North => element.from[2],
+                    BlockFace::South => element.to[2],
+                    BlockFace::West => element.from[0],
+                    BlockFace::East => element.to[0],
+                };
+                // Adding zero turns `-0.0` into `0.0` so both compare equal.
+                let key = (direction, (plane + 0.0).to_bits(), face.texture.0.as_str());
+
+                let group_index = *group_indices.entry(key).or_insert_with(|| {
+                    groups.push(Vec::new());
+                    groups.len() - 1
+                });
+                groups[group_index].push((index, direction));
+            }
+        }
+
+        groups
+    }
+
+    /// Returns the bounding box of the model's [`elements`][Self::elements]
+    /// after the display [`Transform`] for the given position has been
+    /// applied, as the minimum and maximum corners.
+    ///
+    /// The coordinates are in the same units as the elements (1/16th of a
+    /// block). The rotation of each element is applied before the display
+    /// transform, but the [`rescale`][ElementRotation::rescale] flag is
+    /// ignored. If the model has no transform for the given position, the
+    /// elements are left untransformed, as in the game.
+    ///
+    /// Returns `None` if the model has no elements. Note that this only looks
+    /// at `self`. To account for elements and transforms inherited from a
+    /// parent model, call this on the result of
+    /// [`ModelResolver::resolve_model()`].
+    ///
+    /// [`ModelResolver::resolve_model()`]: crate::api::ModelResolver::resolve_model
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::schemas::models::*;
+    /// let model = ModelBuilder::new()
+    ///     .element(Element {
+    ///         from: [4.0, 0.0, 4.0],
+    ///         to: [12.0, 8.0, 12.0],
+    ///         ..Default::default()
+    ///     })
+    ///     .display(
+    ///         DisplayPosition::Gui,
+    ///         Transform {
+    ///             scale: [2.0, 2.0, 2.0],
+    ///             ..Default::default()
+    ///         },
+    ///     )
+    ///     .build();
+    ///
+    /// // Scaling happens about the center of the block.
+    /// assert_eq!(
+    ///     model.display_transformed_bounds(DisplayPosition::Gui),
+    ///     Some(([0.0, -8.0, 0.0], [16.0, 8.0, 16.0]))
+    /// );
+    /// assert_eq!(
+    ///     model.display_transformed_bounds(DisplayPosition::Ground),
+    ///     Some(([4.0, 0.0, 4.0], [12.0, 8.0, 12.0]))
+    /// );
+    /// ```
+    pub fn display_transformed_bounds(
+        &self,
+        position: DisplayPosition,
+    ) -> Option<([f32; 3], [f32; 3])> {
+        let transform = self
+            .display
+            .as_ref()
+            .and_then(|display| display.transform(position))
+            .cloned()
+            .unwrap_or_default();
+        let transform = &transform;
+
+        let mut corners = self.elements.iter().flatten().flat_map(|element| {
+            let [from, to] = [element.from, element.to];
+            (0..8).map(move |i| {
+                let corner = [
+                    if i & 1 == 0 { from[0] } else { to[0] },
+                    if i & 2 == 0 { from[1] } else { to[1] },
+                    if i & 4 == 0 { from[2] } else { to[2] },
+                ];
+                let corner = element.rotation.apply(corner);
+                transform.apply(corner)
+            })
+        });
+
+        let first = corners.next()?;
+        Some(corners.fold((first, first), |(mut min, mut max), corner| {
+            for axis in 0..3 {
+                min[axis] = min[axis].min(corner[axis]);
+                max[axis] = max[axis].max(corner[axis]);
+            }
+            (min, max)
+        }))
+    }
+}
+
+/// A builder for constructing a [`Model`] programmatically.
+///
+/// # Example
+///
+/// ```
+/// # use minecraft_assets::schemas::models::*;
+/// let model = ModelBuilder::new()
+///     .parent("block/cube_all")
+///     .texture("all", "block/stone")
+///     .texture("particle", "#all")
+///     .display(
+///         DisplayPosition::Gui,
+///         Transform {
+///             rotation: [30.0, 225.0, 0.0],
+///             ..Default::default()
+///         },
+///     )
+///     .build();
+///
+/// assert_eq!(model.parent.as_deref(), Some("block/cube_all"));
+///
+/// let textures = model.textures.as_ref().unwrap();
+/// assert_eq!(textures.len(), 2);
+/// assert_eq!(textures["all"].0, "block/stone");
+///
+/// let display = model.display.as_ref().unwrap();
+/// assert_eq!(
+///     display.transform(DisplayPosition::Gui).unwrap().rotation,
+///     [30.0, 225.0, 0.0]
+/// );
+/// assert!(display.head.is_none());
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct ModelBuilder {
+    model: Model,
+}
+
+impl ModelBuilder {
+    /// Returns a new builder for an empty [`Model`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the [`parent`][Model::parent] of the model.
+    pub fn parent(mut self, parent: impl Into<String>) -> Self {
+        self.model.parent = Some(parent.into());
+        self
+    }
+
+    /// Sets the value of a texture variable, replacing any previous value of
+    /// the same variable.
+    pub fn texture(mut self, variable: impl Into<String>, value: impl Into<Texture>) -> Self {
+        self.model
+            .textures
+            .get_or_insert_with(Default::default)
+            .insert(variable.into(), value.into());
+        self
+    }
+
+    /// Appends an [`Element`] to the model's geometry.
+    pub fn element(mut self, element: Element) -> Self {
+        self.model
+            .elements
+            .get_or_insert_with(Default::default)
+            .push(element);
+        self
+    }
+
+    /// Sets how the model is displayed in the given position.
+    pub fn display(mut self, position: DisplayPosition, transform: Transform) -> Self {
+        self.model
+            .display
+            .get_or_insert_with(Default::default)
+            .set_transform(position, transform);
+        self
+    }
+
+    /// Returns the constructed [`Model`].
+    pub fn build(self) -> Model {
+        self.model
+    }
 }
 
 /// Specifies how a [`Model`] is displayed in different views.
@@ -87,33 +726,88 @@ pub struct Model {
 pub struct Display {
     /// How the model is displayed when held in the right hand in third-person
     /// view.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub thirdperson_righthand: Option<Transform>,
 
     /// How the model is displayed when held in the left hand in third-person
     /// view.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub thirdperson_lefthand: Option<Transform>,
 
     /// How the model is displayed when held in the right hand in first-person
     /// view.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub firstperson_righthand: Option<Transform>,
 
     /// How the model is displayed when held in the left hand in first-person
     /// view.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub firstperson_lefthand: Option<Transform>,
 
     /// How the model is displayed in the GUI (e.g., in the inventory).
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub gui: Option<Transform>,
 
     /// How the model is displayed when worn on the player's head.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub head: Option<Transform>,
 
     /// How the model is displayed when on the ground.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ground: Option<Transform>,
 
     /// How the model is displayed in an item frame.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub fixed: Option<Transform>,
 }
 
+impl Display {
+    /// Returns the [`Transform`] used in the given position, if any.
+    pub fn transform(&self, position: DisplayPosition) -> Option<&Transform> {
+        match position {
+            DisplayPosition::ThirdpersonRighthand => self.thirdperson_righthand.as_ref(),
+            DisplayPosition::ThirdpersonLefthand => self.thirdperson_lefthand.as_ref(),
+            DisplayPosition::FirstpersonRighthand => self.firstperson_righthand.as_ref(),
+            DisplayPosition::FirstpersonLefthand => self.firstperson_lefthand.as_ref(),
+            DisplayPosition::Gui => self.gui.as_ref(),
+            DisplayPosition::Head => self.head.as_ref(),
+            DisplayPosition::Ground => self.ground.as_ref(),
+            DisplayPosition::Fixed => self.fixed.as_ref(),
+        }
+    }
+
+    /// Sets the [`Transform`] used in the given position.
+    pub fn set_transform(&mut self, position: DisplayPosition, transform: Transform) {
+        let slot = match position {
+            DisplayPosition::ThirdpersonRighthand => &mut self.thirdperson_righthand,
+            DisplayPosition::ThirdpersonLefthand => &mut self.thirdperson_lefthand,
+            DisplayPosition::FirstpersonRighthand => &mut self.firstperson_righthand,
+            DisplayPosition::FirstpersonLefthand => &mut self.firstperson_lefthand,
+            DisplayPosition::Gui => &mut self.gui,
+            DisplayPosition::Head => &mut self.head,
+            DisplayPosition::Ground => &mut self.ground,
+            DisplayPosition::Fixed => &mut self.fixed,
+        };
+        *slot = Some(transform);
+    }
+}
+
+/// One of the places in which a model can be displayed, corresponding to the
+/// fields of [`Display`].
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+#[allow(missing_docs)]
+pub enum DisplayPosition {
+    ThirdpersonRighthand,
+    ThirdpersonLefthand,
+    FirstpersonRighthand,
+    FirstpersonLefthand,
+    Gui,
+    Head,
+    Ground,
+    Fixed,
+}
+
 /// Specifies the position, rotation, and scale at which a model is displayed.
 ///
 /// Note that translations are applied to the model before rotations.
@@ -147,6 +841,38 @@ impl Transform {
     pub(crate) const fn ones() -> [f32; 3] {
         [1.0; 3]
     }
+
+    /// Applies this transform to a point given in the coordinates of a model's
+    /// elements (1/16th of a block).
+    ///
+    /// As in the game, the point is scaled and then rotated about the center
+    /// of the block (`[8, 8, 8]`), and then translated. The rotation is
+    /// applied about the Z axis first, then the Y axis, then the X axis.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::schemas::models::*;
+    /// let transform = Transform {
+    ///     rotation: [0.0, 90.0, 0.0],
+    ///     translation: [0.0, 1.0, 0.0],
+    ///     scale: [0.5, 0.5, 0.5],
+    /// };
+    ///
+    /// let [x, y, z] = transform.apply([16.0, 16.0, 8.0]);
+    /// assert!((x - 8.0).abs() < 1e-4);
+    /// assert!((y - 13.0).abs() < 1e-4);
+    /// assert!((z - 4.0).abs() < 1e-4);
+    /// ```
+    pub fn apply(&self, point: [f32; 3]) -> [f32; 3] {
+        let mut point = [0, 1, 2].map(|axis| (point[axis] - 8.0) * self.scale[axis]);
+
+        point = rotate_about(point, Axis::Z, self.rotation[2]);
+        point = rotate_about(point, Axis::Y, self.rotation[1]);
+        point = rotate_about(point, Axis::X, self.rotation[0]);
+
+        [0, 1, 2].map(|axis| point[axis] + 8.0 + self.translation[axis])
+    }
 }
 
 impl Default for Transform {
@@ -209,15 +935,49 @@ impl Default for Transform {
 /// }
 /// ```
 ///
+/// ## Null values
+///
+/// Some generated models use `null` as a placeholder value, e.g.,
+/// `"layer0": null`. Such variables are treated as if they were absent: they
+/// are dropped when deserializing, so any reference to them (e.g., `"#layer0"`)
+/// is left unresolved and is reported by [`unresolved()`][Self::unresolved].
+///
+/// ```
+/// # use minecraft_assets::schemas::models::*;
+/// let mut textures: Textures = serde_json::from_str(r##"{
+///     "layer0": null,
+///     "particle": "#layer0"
+/// }"##).unwrap();
+///
+/// assert_eq!(textures.get("layer0"), None);
+///
+/// textures.resolve(&Textures::default());
+/// assert_eq!(textures.unresolved(), vec!["#layer0"]);
+/// ```
+///
 /// [resource location]: <https://minecraft.fandom.com/wiki/Model#File_path>
 #[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq, Eq)]
 pub struct Textures {
     /// The values of all texture variables by name.
-    #[serde(flatten)]
+    #[serde(flatten, deserialize_with = "Textures::deserialize_variables")]
     pub variables: HashMap<String, Texture>,
 }
 
 impl Textures {
+    /// Deserializes the texture variables, dropping those whose value is
+    /// `null`.
+    fn deserialize_variables<'de, D>(deserializer: D) -> Result<HashMap<String, Texture>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let variables = HashMap::<String, Option<Texture>>::deserialize(deserializer)?;
+
+        Ok(variables
+            .into_iter()
+            .filter_map(|(name, texture)| Some((name, texture?)))
+            .collect())
+    }
+
     /// Attempts to resolve each of the texture variables in `self` using the
     /// values present in `other`.
     ///
@@ -251,6 +1011,46 @@ impl Textures {
         }
     }
 
+    /// Returns the values of all texture variables that still reference
+    /// another texture variable (i.e., that begin with `#`), sorted and
+    /// deduplicated.
+    ///
+    /// After resolving a model with [`ModelResolver::resolve_textures()`],
+    /// any value returned here refers to a variable that none of the models
+    /// in the parent chain define.
+    ///
+    /// [`ModelResolver::resolve_textures()`]: crate::api::ModelResolver::resolve_textures
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::schemas::models::*;
+    /// use maplit::hashmap;
+    ///
+    /// let mut textures = Textures::from(hashmap! {
+    ///     "foo" => "#foobar",
+    ///     "bar" => "#barvar",
+    ///     "baz" => "#foobar",
+    /// });
+    ///
+    /// textures.resolve(&Textures::from(hashmap! {
+    ///     "barvar" => "herobrine",
+    /// }));
+    ///
+    /// assert_eq!(textures.unresolved(), vec!["#foobar"]);
+    /// ```
+    pub fn unresolved(&self) -> Vec<&str> {
+        let mut unresolved: Vec<&str> = self
+            .values()
+            .filter(|texture| texture.reference().is_some())
+            .map(|texture| texture.0.as_str())
+            .collect();
+
+        unresolved.sort_unstable();
+        unresolved.dedup();
+        unresolved
+    }
+
     /// Merges the values from `other` into `self`.
     ///
     /// # Example
@@ -283,6 +1083,214 @@ impl Textures {
             self.insert(name, texture);
         }
     }
+
+    /// Returns the value of the given texture variable, or `None` if it is not
+    /// set.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::schemas::models::*;
+    /// use maplit::hashmap;
+    ///
+    /// let textures = Textures::from(hashmap! {
+    ///     "all" => "block/stone",
+    ///     "particle" => "#all",
+    /// });
+    ///
+    /// assert_eq!(textures.get("all"), Some("block/stone"));
+    /// assert_eq!(textures.get("particle"), Some("#all"));
+    /// assert_eq!(textures.get("side"), None);
+    /// ```
+    pub fn get(&self, variable: &str) -> Option<&str> {
+        self.variables
+            .get(variable)
+            .map(|texture| texture.0.as_str())
+    }
+
+    /// Returns the values of the `layer0`, `layer1`, ... texture variables,
+    /// in order of their index.
+    ///
+    /// Item models that inherit from `builtin/generated` (like
+    /// `item/generated`) are drawn as a stack of flat sprites, one for each
+    /// layer, with `layer0` at the bottom. Variables whose names are not
+    /// `layer` followed by a number are ignored.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::schemas::models::*;
+    /// use maplit::hashmap;
+    ///
+    /// let textures = Textures::from(hashmap! {
+    ///     "layer10" => "item/potion_sparkle",
+    ///     "layer0" => "item/potion",
+    ///     "layer2" => "item/potion_overlay",
+    ///     "particle" => "item/potion",
+    ///     "layer" => "item/not_a_layer",
+    /// });
+    ///
+    /// assert_eq!(
+    ///     textures.layers(),
+    ///     vec!["item/potion", "item/potion_overlay", "item/potion_sparkle"]
+    /// );
+    /// ```
+    pub fn layers(&self) -> Vec<&str> {
+        let mut layers: Vec<(u32, &str)> = self
+            .variables
+            .iter()
+            .filter_map(|(name, texture)| {
+                let index = name.strip_prefix("layer")?;
+                if !index.bytes().all(|b| b.is_ascii_digit()) {
+                    return None;
+                }
+                Some((index.parse().ok()?, texture.0.as_str()))
+            })
+            .collect();
+
+        layers.sort_unstable();
+        layers.into_iter().map(|(_, texture)| texture).collect()
+    }
+
+    /// Follows the given texture reference through the texture variables in
+    /// `self` and returns the id of the texture that it ends up at.
+    ///
+    /// The reference may be a texture variable (e.g., `#side`) or a texture
+    /// location (e.g., `block/stone` or `minecraft:block/stone`), as found in
+    /// the [`texture`][ElementFace::texture] of a face. The returned id is in
+    /// its [canonical form].
+    ///
+    /// Returns `None` if the chain of variables ends at a variable that is not
+    /// set, or if it loops back on itself.
+    ///
+    /// [canonical form]: crate::api::ResourceIdentifier::to_canonical
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::schemas::models::*;
+    /// use minecraft_assets::api::ResourceIdentifier;
+    /// use maplit::hashmap;
+    ///
+    /// let textures = Textures::from(hashmap! {
+    ///     "side" => "block/oak_log",
+    ///     "particle" => "#side",
+    ///     "loop" => "#loop",
+    /// });
+    ///
+    /// let expected = ResourceIdentifier::texture("minecraft:block/oak_log");
+    /// assert_eq!(textures.resolve_reference("#particle"), Some(expected.clone()));
+    /// assert_eq!(textures.resolve_reference("#side"), Some(expected.clone()));
+    /// assert_eq!(textures.resolve_reference("block/oak_log"), Some(expected));
+    ///
+    /// assert_eq!(textures.resolve_reference("#end"), None);
+    /// assert_eq!(textures.resolve_reference("#loop"), None);
+    /// ```
+    pub fn resolve_reference(&self, reference: &str) -> Option<ResourceIdentifier<'static>> {
+        let mut visited = HashSet::new();
+        let mut current = reference;
+
+        while let Some(variable) = current.strip_prefix('#') {
+            if !visited.insert(variable) {
+                return None;
+            }
+            current = self.get(variable)?;
+        }
+
+        Some(
+            ResourceIdentifier::texture(current)
+                .to_canonical()
+                .to_owned(),
+        )
+    }
+
+    /// Sets the value of the given texture variable, returning the previous
+    /// value if there was one.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::schemas::models::*;
+    /// let mut textures = Textures::default();
+    ///
+    /// assert_eq!(textures.insert("all", "block/stone"), None);
+    /// assert_eq!(
+    ///     textures.insert("all", "block/dirt"),
+    ///     Some(Texture::from("block/stone"))
+    /// );
+    /// assert_eq!(textures.get("all"), Some("block/dirt"));
+    /// ```
+    pub fn insert(
+        &mut self,
+        variable: impl Into<String>,
+        value: impl Into<Texture>,
+    ) -> Option<Texture> {
+        self.variables.insert(variable.into(), value.into())
+    }
+
+    /// Returns an iterator over the names and values of all texture
+    /// variables, in arbitrary order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::schemas::models::*;
+    /// use maplit::hashmap;
+    ///
+    /// let textures = Textures::from(hashmap! {
+    ///     "all" => "block/stone",
+    ///     "particle" => "#all",
+    /// });
+    ///
+    /// let references: Vec<&str> = textures
+    ///     .iter()
+    ///     .filter(|(_, texture)| texture.reference().is_some())
+    ///     .map(|(name, _)| name)
+    ///     .collect();
+    ///
+    /// assert_eq!(references, vec!["particle"]);
+    /// ```
+    pub fn iter(&self) -> TexturesIter<'_> {
+        TexturesIter(self.variables.iter())
+    }
+}
+
+/// An iterator over the texture variables of a [`Textures`].
+///
+/// Returned by [`Textures::iter()`].
+#[derive(Debug, Clone)]
+pub struct TexturesIter<'a>(hash_map::Iter<'a, String, Texture>);
+
+impl<'a> Iterator for TexturesIter<'a> {
+    type Item = (&'a str, &'a Texture);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0
+            .next()
+            .map(|(name, texture)| (name.as_str(), texture))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<'a> IntoIterator for &'a Textures {
+    type Item = (&'a str, &'a Texture);
+    type IntoIter = TexturesIter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl IntoIterator for Textures {
+    type Item = (String, Texture);
+    type IntoIter = hash_map::IntoIter<String, Texture>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.variables.into_iter()
+    }
 }
 
 impl<K, V> From<HashMap<K, V>> for Textures
@@ -400,7 +1408,7 @@ impl Texture {
     pub fn resolve<'a>(&'a self, substitutions: &'a Textures) -> Option<&'a str> {
         if let Some(reference) = self.reference() {
             if let Some(substitution) = substitutions.get(reference) {
-                return Some(&substitution.0);
+                return Some(substitution);
             }
         }
         None
@@ -443,12 +1451,48 @@ pub struct Element {
     /// Specifies if shadows are rendered (`true` - default), or not (`false`).
     #[serde(default = "Element::default_shade")]
     pub shade: bool,
+
+    /// Any other fields present on the element that are not part of the
+    /// vanilla format.
+    ///
+    /// Model editors like Blockbench add fields such as `"__comment"` or
+    /// `"name"` to elements. These are kept here so that a model can be
+    /// loaded, edited, and saved without losing them.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::schemas::models::*;
+    /// let json = r#"{
+    ///     "__comment": "Box1",
+    ///     "from": [0.0, 0.0, 0.0],
+    ///     "to": [16.0, 16.0, 16.0],
+    ///     "faces": {}
+    /// }"#;
+    ///
+    /// let element: Element = serde_json::from_str(json).unwrap();
+    /// assert_eq!(element.extra["__comment"], "Box1");
+    ///
+    /// let round_trip = serde_json::to_value(&element).unwrap();
+    /// assert_eq!(round_trip["__comment"], "Box1");
+    /// ```
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 impl Element {
     pub(crate) const fn default_shade() -> bool {
         true
     }
+
+    /// Like [`Model::eq_semantic()`], ignores [`extra`][Self::extra].
+    fn eq_semantic(&self, other: &Self) -> bool {
+        self.from == other.from
+            && self.to == other.to
+            && self.faces == other.faces
+            && self.rotation == other.rotation
+            && self.shade == other.shade
+    }
 }
 
 impl Default for Element {
@@ -459,6 +1503,7 @@ impl Default for Element {
             faces: Default::default(),
             rotation: Default::default(),
             shade: Self::default_shade(),
+            extra: Default::default(),
         }
     }
 }
@@ -488,6 +1533,16 @@ impl ElementRotation {
     pub(crate) const fn default_rescale() -> bool {
         false
     }
+
+    /// Rotates a point of an element about the [`origin`][Self::origin] by
+    /// the [`angle`][Self::angle] of this rotation.
+    ///
+    /// The [`rescale`][Self::rescale] flag is ignored.
+    pub fn apply(&self, point: [f32; 3]) -> [f32; 3] {
+        let point = [0, 1, 2].map(|axis| point[axis] - self.origin[axis]);
+        let point = rotate_about(point, self.axis, self.angle);
+        [0, 1, 2].map(|axis| point[axis] + self.origin[axis])
+    }
 }
 
 impl Default for ElementRotation {
@@ -514,6 +1569,7 @@ pub struct ElementFace {
     /// The texture behavior is inconsistent if UV extends below 0 or above 16.
     /// If the numbers of `x1` and `x2` are swapped (e.g. from `0, 0, 16, 16` to
     /// `16, 0, 0, 16`), the texture flips.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub uv: Option<[f32; 4]>,
 
     /// Specifies the texture as [texture variable] prepended with a `#`.
@@ -531,7 +1587,7 @@ pub struct ElementFace {
     ///
     /// `bottom` may also be used in the latest versions instead of `down`,
     /// despite appearing only once in the actual game assets.
-    #[serde(rename = "cullface")]
+    #[serde(rename = "cullface", skip_serializing_if = "Option::is_none")]
     pub cull_face: Option<BlockFace>,
 
     /// Rotates the texture by the specified number of degrees.
@@ -604,6 +1660,24 @@ pub enum PredicateValue {
     Float(f32),
 }
 
+impl PredicateValue {
+    /// Returns the value as an `f64`, regardless of how it was written.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::schemas::models::*;
+    /// assert_eq!(PredicateValue::Int(1).as_f64(), 1.0);
+    /// assert_eq!(PredicateValue::Float(0.5).as_f64(), 0.5);
+    /// ```
+    pub fn as_f64(&self) -> f64 {
+        match *self {
+            Self::Int(value) => f64::from(value),
+            Self::Float(value) => f64::from(value),
+        }
+    }
+}
+
 /// The two possible ways to shade a model in the UI.
 #[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[serde(rename_all = "lowercase")]
@@ -640,3 +1714,20 @@ pub enum BlockFace {
     West,
     East,
 }
+
+/// Rotates a point counterclockwise about the given axis through the origin
+/// by the given angle in degrees, following the right-hand rule.
+fn rotate_about(point: [f32; 3], axis: Axis, degrees: f32) -> [f32; 3] {
+    if degrees == 0.0 {
+        return point;
+    }
+
+    let (sin, cos) = degrees.to_radians().sin_cos();
+    let [x, y, z] = point;
+
+    match axis {
+        Axis::X => [x, y * cos - z * sin, y * sin + z * cos],
+        Axis::Y => [x * cos + z * sin, y, -x * sin + z * cos],
+        Axis::Z => [x * cos - y * sin, x * sin + y * cos, z],
+    }
+}
@@ -0,0 +1,113 @@
+//! Data types for `assets/<namespace>/optifine/**/*.properties`.
+//!
+//! Start here: [`Properties`].
+//!
+//! These files are not part of vanilla Minecraft. They are read by the
+//! [OptiFine] mod, e.g., to configure connected textures (CTM).
+//!
+//! [OptiFine]: <https://optifine.readthedocs.io/>
+
+use std::{
+    collections::HashMap,
+    ops::{Deref, DerefMut},
+};
+
+/// A `.properties` file as stored in the `assets/<namespace>/optifine/`
+/// directory, as a map from each key to its value.
+///
+/// The file is parsed with a simplified version of the Java `.properties`
+/// format:
+///
+/// * Each non-blank line holds one `key=value` pair. A `:` may be used
+///   instead of `=`, and whitespace around the key and the value is ignored.
+/// * Lines whose first non-blank character is `#` or `!` are comments.
+/// * A line that ends with a `\` is continued on the next line.
+/// * A line with no `=` or `:` holds a key with an empty value.
+/// * If a key appears more than once, the last value wins.
+///
+/// Escape sequences (e.g., `\u00e9`) are not interpreted.
+///
+/// See also the [OptiFine documentation].
+///
+/// [OptiFine documentation]: <https://optifine.readthedocs.io/ctm.html>
+///
+/// # Example
+///
+/// ```
+/// # use minecraft_assets::schemas::optifine::*;
+/// let text = "
+///     ## Connect glass panes
+///     method=ctm
+///     matchBlocks=glass \\
+///         white_stained_glass
+///     tiles: 0-46
+/// ";
+///
+/// let properties = Properties::parse(text);
+///
+/// assert_eq!(properties["method"], "ctm");
+/// assert_eq!(properties["matchBlocks"], "glass white_stained_glass");
+/// assert_eq!(properties["tiles"], "0-46");
+/// assert_eq!(properties.len(), 3);
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Properties(pub HashMap<String, String>);
+
+impl Properties {
+    /// Parses the contents of a `.properties` file.
+    ///
+    /// Parsing never fails: lines that are not understood are read as keys
+    /// with empty values.
+    pub fn parse(text: &str) -> Self {
+        let mut properties = HashMap::new();
+        let mut lines = text.lines();
+
+        while let Some(line) = lines.next() {
+            let line = line.trim_start();
+            if line.is_empty() || line.starts_with('#') || line.starts_with('!') {
+                continue;
+            }
+
+            // Join continued lines, dropping the leading whitespace of each.
+            let mut line = line.to_string();
+            while line.ends_with('\\') {
+                line.pop();
+                match lines.next() {
+                    Some(next) => line.push_str(next.trim_start()),
+                    None => break,
+                }
+            }
+
+            let (key, value) = match line.find(['=', ':']) {
+                Some(index) => (&line[..index], &line[index + 1..]),
+                None => (&line[..], ""),
+            };
+
+            properties.insert(key.trim().to_string(), value.trim().to_string());
+        }
+
+        Self(properties)
+    }
+
+    /// Parses the contents of a `.properties` file from bytes.
+    ///
+    /// Invalid UTF-8 sequences are replaced with
+    /// [`U+FFFD REPLACEMENT CHARACTER`][char::REPLACEMENT_CHARACTER].
+    pub fn from_slice(bytes: &[u8]) -> Self {
+        Self::parse(&String::from_utf8_lossy(bytes))
+    }
+}
+
+impl Deref for Properties {
+    type Target = HashMap<String, String>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for Properties {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
@@ -0,0 +1,38 @@
+//! Serde-(de)serializable data types for
+//! `assets/<namespace>/particles/*.json`.
+//!
+//! Start here: [`ParticleDefinition`].
+//!
+//! See <https://minecraft.fandom.com/wiki/Resource_pack#Particles>.
+
+use serde::{Deserialize, Serialize};
+
+/// A particle definition as stored in the `assets/<namespace>/particles/`
+/// directory.
+///
+/// # Example
+///
+/// ```
+/// # use minecraft_assets::schemas::particles::*;
+/// let json = r#"{
+///     "textures": [
+///         "minecraft:flame"
+///     ]
+/// }"#;
+///
+/// let particle: ParticleDefinition = serde_json::from_str(json).unwrap();
+/// assert_eq!(particle.textures, vec!["minecraft:flame"]);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq, Eq)]
+pub struct ParticleDefinition {
+    /// The [resource locations] of the textures of the particle, relative to
+    /// `textures/particle/`. Particles with more than one texture cycle
+    /// through them as they age.
+    ///
+    /// Some particles (e.g., those rendered from block textures) list no
+    /// textures.
+    ///
+    /// [resource locations]: <https://minecraft.fandom.com/wiki/Model#File_path>
+    #[serde(default)]
+    pub textures: Vec<String>,
+}
@@ -0,0 +1,237 @@
+//! Serde-(de)serializable data types for `data/<namespace>/recipes/*.json`.
+//!
+//! Start here: [`Recipe`].
+//!
+//! See <https://minecraft.fandom.com/wiki/Recipe#JSON_format>.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+/// A recipe as stored in the `data/<namespace>/recipes/` directory.
+///
+/// The kind of recipe is determined by its `"type"` field, e.g.,
+/// `"minecraft:crafting_shaped"`. The `minecraft:` prefix may be omitted.
+///
+/// See also the corresponding section of the [wiki page].
+///
+/// [wiki page]: <https://minecraft.fandom.com/wiki/Recipe#JSON_format>
+///
+/// # Example
+///
+/// ```
+/// # use minecraft_assets::schemas::recipes::*;
+/// let json = r#"{
+///     "type": "minecraft:crafting_shapeless",
+///     "group": "planks",
+///     "ingredients": [
+///         { "tag": "minecraft:oak_logs" }
+///     ],
+///     "result": { "item": "minecraft:oak_planks", "count": 4 }
+/// }"#;
+///
+/// let recipe: Recipe = serde_json::from_str(json).unwrap();
+/// let shapeless = match recipe {
+///     Recipe::CraftingShapeless(shapeless) => shapeless,
+///     _ => unreachable!(),
+/// };
+///
+/// assert_eq!(shapeless.group.as_deref(), Some("planks"));
+/// assert_eq!(shapeless.result.item, "minecraft:oak_planks");
+/// assert_eq!(shapeless.result.count, 4);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[serde(tag = "type")]
+pub enum Recipe {
+    /// A recipe for a crafting table whose ingredients must be placed in a
+    /// particular pattern.
+    #[serde(rename = "minecraft:crafting_shaped", alias = "crafting_shaped")]
+    CraftingShaped(ShapedRecipe),
+
+    /// A recipe for a crafting table whose ingredients may be placed anywhere
+    /// in the grid.
+    #[serde(rename = "minecraft:crafting_shapeless", alias = "crafting_shapeless")]
+    CraftingShapeless(ShapelessRecipe),
+
+    /// A recipe for a furnace.
+    #[serde(rename = "minecraft:smelting", alias = "smelting")]
+    Smelting(SmeltingRecipe),
+
+    /// Any other type of recipe that is not yet supported by this crate (e.g.,
+    /// `"minecraft:crafting_special_firework_rocket"`).
+    ///
+    /// The contents of the recipe are discarded. This variant cannot be
+    /// serialized.
+    #[serde(other, skip_serializing)]
+    Other,
+}
+
+/// A crafting recipe whose ingredients must be placed in a particular
+/// pattern.
+///
+/// # Example
+///
+/// ```
+/// # use minecraft_assets::schemas::recipes::*;
+/// let json = r#"{
+///     "type": "minecraft:crafting_shaped",
+///     "pattern": [
+///         "PP",
+///         "PP"
+///     ],
+///     "key": {
+///         "P": { "item": "minecraft:oak_planks" }
+///     },
+///     "result": { "item": "minecraft:crafting_table" }
+/// }"#;
+///
+/// let recipe: Recipe = serde_json::from_str(json).unwrap();
+/// let shaped = match recipe {
+///     Recipe::CraftingShaped(shaped) => shaped,
+///     _ => unreachable!(),
+/// };
+///
+/// assert_eq!(shaped.pattern, vec!["PP", "PP"]);
+/// assert_eq!(
+///     shaped.key["P"],
+///     Ingredient::Single(IngredientChoice::Item {
+///         item: String::from("minecraft:oak_planks")
+///     })
+/// );
+/// assert_eq!(shaped.result.count, 1);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct ShapedRecipe {
+    /// Recipes with the same group are shown together in the recipe book.
+    pub group: Option<String>,
+
+    /// The rows of the crafting grid, from top to bottom. Each character in a
+    /// row refers to an ingredient in [`key`][Self::key], and a space means
+    /// that the slot must be empty.
+    pub pattern: Vec<String>,
+
+    /// Maps the characters used in [`pattern`][Self::pattern] to ingredients.
+    pub key: HashMap<String, Ingredient>,
+
+    /// The item produced by the recipe.
+    pub result: RecipeResult,
+}
+
+/// A crafting recipe whose ingredients may be placed anywhere in the grid.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct ShapelessRecipe {
+    /// Recipes with the same group are shown together in the recipe book.
+    pub group: Option<String>,
+
+    /// The ingredients of the recipe, one per occupied slot.
+    pub ingredients: Vec<Ingredient>,
+
+    /// The item produced by the recipe.
+    pub result: RecipeResult,
+}
+
+/// A furnace recipe.
+///
+/// # Example
+///
+/// ```
+/// # use minecraft_assets::schemas::recipes::*;
+/// let json = r#"{
+///     "type": "minecraft:smelting",
+///     "ingredient": { "item": "minecraft:iron_ore" },
+///     "result": "minecraft:iron_ingot",
+///     "experience": 0.7
+/// }"#;
+///
+/// let recipe: Recipe = serde_json::from_str(json).unwrap();
+/// let smelting = match recipe {
+///     Recipe::Smelting(smelting) => smelting,
+///     _ => unreachable!(),
+/// };
+///
+/// assert_eq!(smelting.result, "minecraft:iron_ingot");
+/// assert_eq!(smelting.cooking_time, 200);
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct SmeltingRecipe {
+    /// Recipes with the same group are shown together in the recipe book.
+    pub group: Option<String>,
+
+    /// The item to be smelted.
+    pub ingredient: Ingredient,
+
+    /// The id of the item produced by the recipe.
+    pub result: String,
+
+    /// The amount of experience granted by the recipe.
+    #[serde(default)]
+    pub experience: f32,
+
+    /// The number of ticks it takes to smelt the item (`200` - default).
+    #[serde(
+        rename = "cookingtime",
+        default = "SmeltingRecipe::default_cooking_time"
+    )]
+    pub cooking_time: u32,
+}
+
+impl SmeltingRecipe {
+    pub(crate) const fn default_cooking_time() -> u32 {
+        200
+    }
+}
+
+/// An ingredient of a recipe.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[serde(untagged)]
+pub enum Ingredient {
+    /// Only one item or tag is accepted.
+    Single(IngredientChoice),
+
+    /// Any one of the listed items or tags is accepted.
+    Multiple(Vec<IngredientChoice>),
+}
+
+impl Ingredient {
+    /// Returns the list of items or tags accepted by this ingredient.
+    pub fn choices(&self) -> &[IngredientChoice] {
+        match self {
+            Self::Single(choice) => std::slice::from_ref(choice),
+            Self::Multiple(choices) => &choices[..],
+        }
+    }
+}
+
+/// An item or tag that is accepted as an [`Ingredient`].
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[serde(untagged)]
+pub enum IngredientChoice {
+    /// A specific item.
+    Item {
+        /// The id of the item.
+        item: String,
+    },
+
+    /// Any item in a tag.
+    Tag {
+        /// The id of the item tag.
+        tag: String,
+    },
+}
+
+/// The item produced by a crafting recipe.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct RecipeResult {
+    /// The id of the item.
+    pub item: String,
+
+    /// The number of items produced (`1` - default).
+    #[serde(default = "RecipeResult::default_count")]
+    pub count: u32,
+}
+
+impl RecipeResult {
+    pub(crate) const fn default_count() -> u32 {
+        1
+    }
+}
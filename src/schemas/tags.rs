@@ -0,0 +1,109 @@
+//! Serde-(de)serializable data types for `data/<namespace>/tags/**/*.json`.
+//!
+//! Start here: [`Tag`].
+//!
+//! See <https://minecraft.fandom.com/wiki/Tag#JSON_format>.
+
+use serde::{Deserialize, Serialize};
+
+/// A tag as stored in the `data/<namespace>/tags/<type>/` directory.
+///
+/// A tag groups together blocks, items, functions, etc. so that they can be
+/// referenced all at once.
+///
+/// See also the corresponding section of the [wiki page].
+///
+/// [wiki page]: <https://minecraft.fandom.com/wiki/Tag#JSON_format>
+///
+/// # Example
+///
+/// ```
+/// # use minecraft_assets::schemas::tags::*;
+/// let json = r##"{
+///     "values": [
+///         "minecraft:oak_log",
+///         "#minecraft:birch_logs",
+///         { "id": "othermod:maple_log", "required": false }
+///     ]
+/// }"##;
+///
+/// let tag: Tag = serde_json::from_str(json).unwrap();
+///
+/// assert!(!tag.replace);
+/// assert_eq!(tag.values[0].id(), "minecraft:oak_log");
+/// assert!(tag.values[1].is_tag_reference());
+/// assert!(!tag.values[2].is_required());
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct Tag {
+    /// Whether the values of this tag replace the values of a tag with the
+    /// same id from a lower-priority pack (`true`), or are appended to them
+    /// (`false` - default).
+    #[serde(default)]
+    pub replace: bool,
+
+    /// The entries of the tag.
+    pub values: Vec<TagEntry>,
+}
+
+/// An entry in a [`Tag`].
+///
+/// An entry's id that starts with `#` refers to another tag, in which case all
+/// of the values of that tag are included.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[serde(untagged)]
+pub enum TagEntry {
+    /// A plain id of a value or a tag.
+    Id(String),
+
+    /// An id of a value or a tag that may be marked as optional.
+    Object {
+        /// The id of the value or tag.
+        id: String,
+
+        /// Whether loading the tag should fail if the value does not exist
+        /// (`true` - default).
+        #[serde(default = "TagEntry::default_required")]
+        required: bool,
+    },
+}
+
+impl TagEntry {
+    pub(crate) const fn default_required() -> bool {
+        true
+    }
+
+    /// Returns the id of the value or tag referenced by this entry, including
+    /// the leading `#` if it refers to a tag.
+    pub fn id(&self) -> &str {
+        match self {
+            Self::Id(id) => id,
+            Self::Object { id, .. } => id,
+        }
+    }
+
+    /// Returns whether this entry refers to another tag rather than a single
+    /// value.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use minecraft_assets::schemas::tags::*;
+    /// let entry = TagEntry::Id(String::from("#minecraft:logs"));
+    /// assert!(entry.is_tag_reference());
+    ///
+    /// let entry = TagEntry::Id(String::from("minecraft:oak_log"));
+    /// assert!(!entry.is_tag_reference());
+    /// ```
+    pub fn is_tag_reference(&self) -> bool {
+        self.id().starts_with('#')
+    }
+
+    /// Returns whether this entry must exist for the tag to load.
+    pub fn is_required(&self) -> bool {
+        match self {
+            Self::Id(_) => true,
+            Self::Object { required, .. } => *required,
+        }
+    }
+}
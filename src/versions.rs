@@ -127,3 +127,194 @@
 //! [^3]: <https://minecraft.fandom.com/wiki/Java_Edition_14w06a#General_2>
 //!
 //! [^4]: <https://minecraft.fandom.com/wiki/Java_Edition_14w25a#Command_format>
+
+use crate::api::{ModelIdentifier, ResourceKind};
+
+/// A Minecraft release whose assets are supported by this crate.
+///
+/// Variants are ordered chronologically, so versions can be compared with `<`
+/// and `>`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[allow(non_camel_case_types)]
+#[allow(missing_docs)]
+pub enum Version {
+    V1_8,
+    V1_9,
+    V1_10,
+    V1_11,
+    V1_12,
+    V1_13,
+    V1_14,
+    V1_15,
+    V1_16,
+    V1_16_2,
+    V1_17,
+    V1_18,
+}
+
+impl Version {
+    /// Returns the `pack_format` number used by the `pack.mcmeta` of resource
+    /// packs for this version.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use minecraft_assets::versions::Version;
+    ///
+    /// assert_eq!(Version::V1_8.pack_format(), 1);
+    /// assert_eq!(Version::V1_16.pack_format(), 5);
+    /// assert_eq!(Version::V1_16_2.pack_format(), 6);
+    /// ```
+    pub fn pack_format(self) -> u32 {
+        match self {
+            Self::V1_8 => 1,
+            Self::V1_9 | Self::V1_10 => 2,
+            Self::V1_11 | Self::V1_12 => 3,
+            Self::V1_13 | Self::V1_14 => 4,
+            Self::V1_15 | Self::V1_16 => 5,
+            Self::V1_16_2 => 6,
+            Self::V1_17 => 7,
+            Self::V1_18 => 8,
+        }
+    }
+
+    /// Returns the earliest version that uses the given `pack_format` number,
+    /// or `None` if the number is not known.
+    ///
+    /// All versions that share a `pack_format` also share the behavior
+    /// described by the other methods of [`Version`], so the returned version
+    /// can be used to query them.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use minecraft_assets::versions::Version;
+    ///
+    /// assert_eq!(Version::from_pack_format(4), Some(Version::V1_13));
+    /// assert_eq!(Version::from_pack_format(6), Some(Version::V1_16_2));
+    /// assert_eq!(Version::from_pack_format(42), None);
+    /// ```
+    pub fn from_pack_format(pack_format: u32) -> Option<Self> {
+        let version = match pack_format {
+            1 => Self::V1_8,
+            2 => Self::V1_9,
+            3 => Self::V1_11,
+            4 => Self::V1_13,
+            5 => Self::V1_15,
+            6 => Self::V1_16_2,
+            7 => Self::V1_17,
+            8 => Self::V1_18,
+            _ => return None,
+        };
+
+        Some(version)
+    }
+
+    /// Returns `true` if this version comes after [The Flattening] (1.13).
+    ///
+    /// [The Flattening]: https://minecraft.fandom.com/wiki/The_Flattening
+    pub fn uses_flattening(self) -> bool {
+        self >= Self::V1_13
+    }
+
+    /// Returns the prefix that blockstates files for this version put in
+    /// front of the bare name of a model of the given kind.
+    ///
+    /// See [`model_reference()`] for details. For kinds other than
+    /// [`BlockModel`] and [`ItemModel`], the prefix is empty.
+    ///
+    /// [`BlockModel`]: ResourceKind::BlockModel
+    /// [`ItemModel`]: ResourceKind::ItemModel
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use minecraft_assets::api::ResourceKind;
+    /// use minecraft_assets::versions::Version;
+    ///
+    /// let kind = ResourceKind::BlockModel;
+    ///
+    /// assert_eq!(Version::V1_12.model_path_prefix(kind), "");
+    /// assert_eq!(Version::V1_13.model_path_prefix(kind), "block/");
+    /// assert_eq!(Version::V1_17.model_path_prefix(kind), "minecraft:block/");
+    /// ```
+    pub fn model_path_prefix(self, kind: ResourceKind) -> &'static str {
+        let (prefix, namespaced_prefix) = match kind {
+            ResourceKind::BlockModel => ("block/", "minecraft:block/"),
+            ResourceKind::ItemModel => ("item/", "minecraft:item/"),
+            _ => return "",
+        };
+
+        if self < Self::V1_13 {
+            ""
+        } else if self < Self::V1_16_2 {
+            prefix
+        } else {
+            namespaced_prefix
+        }
+    }
+
+    /// Returns the name given to the only variant of blocks that have a single
+    /// variant in their blockstates file.
+    ///
+    /// This is `"normal"` prior to 1.13, and `""` from 1.13 onwards.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use minecraft_assets::versions::Version;
+    ///
+    /// assert_eq!(Version::V1_12.single_variant_key(), "normal");
+    /// assert_eq!(Version::V1_13.single_variant_key(), "");
+    /// ```
+    pub fn single_variant_key(self) -> &'static str {
+        if self.uses_flattening() {
+            ""
+        } else {
+            "normal"
+        }
+    }
+}
+
+/// Returns how a blockstates file for the given version refers to the model
+/// with the given bare name.
+///
+/// * Prior to 1.13, model references have no prefix (`oak_planks`).
+/// * From 1.13 until 1.16.2, model references are prefixed with `block/` or
+///   `item/` (`block/oak_planks`).
+/// * From 1.16.2 onwards, model references also include the `minecraft:`
+///   namespace (`minecraft:block/oak_planks`).
+///
+/// Any existing `block/` or `item/` prefix on `name` is replaced. For kinds
+/// other than [`BlockModel`] and [`ItemModel`], `name` is returned unchanged.
+///
+/// [`BlockModel`]: ResourceKind::BlockModel
+/// [`ItemModel`]: ResourceKind::ItemModel
+///
+/// # Example
+///
+/// ```
+/// use minecraft_assets::api::ResourceKind;
+/// use minecraft_assets::versions::{model_reference, Version};
+///
+/// let kind = ResourceKind::BlockModel;
+///
+/// assert_eq!(model_reference("oak_planks", kind, Version::V1_8), "oak_planks");
+/// assert_eq!(model_reference("oak_planks", kind, Version::V1_13), "block/oak_planks");
+/// assert_eq!(
+///     model_reference("oak_planks", kind, Version::V1_16_2),
+///     "minecraft:block/oak_planks"
+/// );
+///
+/// let kind = ResourceKind::ItemModel;
+///
+/// assert_eq!(model_reference("item/diamond_hoe", kind, Version::V1_14), "item/diamond_hoe");
+/// ```
+pub fn model_reference(name: &str, kind: ResourceKind, version: Version) -> String {
+    if !matches!(kind, ResourceKind::BlockModel | ResourceKind::ItemModel) {
+        return String::from(name);
+    }
+
+    let name = ModelIdentifier::model_name(name);
+    format!("{}{}", version.model_path_prefix(kind), name)
+}
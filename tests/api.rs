@@ -4,8 +4,8 @@ use assert_matches::assert_matches;
 use std::{io, path::PathBuf};
 
 use minecraft_assets::api::{
-    AssetPack, EnumerateResources, FileSystemResourceProvider, ModelIdentifier, ResourceKind,
-    ResourceProvider,
+    AssetPack, EnumerateResources, FileSystemResourceProvider, Manifest, ModelChainEntry,
+    ModelIdentifier, ModelResolver, ResourceIdentifier, ResourceKind, ResourceProvider,
 };
 
 mod common;
@@ -113,6 +113,22 @@ fn load_block_model_recursive(assets: &AssetPack, version: &str) {
     assert_eq!(models, expected);
 }
 
+fn load_block_model_resolved(assets: &AssetPack) {
+    let model = assets.load_block_model_resolved("cube_all").unwrap();
+    let cube = assets.load_block_model("cube").unwrap();
+
+    assert_eq!(model.parent, None);
+
+    let elements = model.elements.unwrap();
+    assert_eq!(elements.len(), cube.elements.unwrap().len());
+
+    // Every face texture of `cube` references a variable that `cube_all` sets
+    // to `#all`, which is left unresolved since `all` is never defined.
+    for face in elements.iter().flat_map(|element| element.faces.values()) {
+        assert_eq!(face.texture.0, "#all");
+    }
+}
+
 fn load_item_model_recursive(assets: &AssetPack, version: &str) {
     let models = assets.load_item_model_recursive("diamond_hoe").unwrap();
 
@@ -133,6 +149,24 @@ fn load_item_model_recursive(assets: &AssetPack, version: &str) {
     assert_eq!(models, expected);
 }
 
+fn load_item_model_recursive_with_builtin(assets: &AssetPack) {
+    let chain = assets
+        .load_item_model_recursive_with_builtin("diamond_hoe")
+        .unwrap();
+
+    let models = assets.load_item_model_recursive("diamond_hoe").unwrap();
+    assert_eq!(chain.len(), models.len() + 1);
+
+    for (entry, model) in chain.iter().zip(models.iter()) {
+        assert_eq!(entry.as_model(), Some(model));
+    }
+
+    assert_eq!(
+        chain.last(),
+        Some(&ModelChainEntry::Builtin(String::from("generated")))
+    );
+}
+
 fn do_api_test(version: &str, flattening: Versions) {
     let assets = get_asset_pack(version);
 
@@ -140,7 +174,9 @@ fn do_api_test(version: &str, flattening: Versions) {
     load_block_model(&assets);
     load_item_model(&assets, version);
     load_block_model_recursive(&assets, version);
+    load_block_model_resolved(&assets);
     load_item_model_recursive(&assets, version);
+    load_item_model_recursive_with_builtin(&assets);
 }
 
 fn get_asset_pack(version: &str) -> AssetPack {
@@ -198,6 +234,23 @@ fn api_1_18() {
     do_api_test("1.18", Versions::Post_1_16_2);
 }
 
+#[test]
+fn full_cube() {
+    let assets = get_asset_pack("1.14");
+
+    assert!(assets.load_block_model("cube").unwrap().is_full_cube());
+    assert!(assets
+        .load_block_model_resolved("stone")
+        .unwrap()
+        .is_full_cube());
+
+    assert!(!assets.load_block_model("slab").unwrap().is_full_cube());
+    assert!(!assets
+        .load_block_model_resolved("stone_slab")
+        .unwrap()
+        .is_full_cube());
+}
+
 #[test]
 fn for_each_blockstates() {
     let assets = get_asset_pack("1.14");
@@ -262,3 +315,88 @@ fn for_each_texture_meta() {
         54,
     );
 }
+
+#[test]
+fn export_manifest() {
+    let assets = get_asset_pack("1.14");
+
+    let manifest = assets.export_manifest("minecraft").unwrap();
+
+    let counts = [
+        (ResourceKind::BlockStates, 677),
+        (ResourceKind::BlockModel, 1201),
+        (ResourceKind::ItemModel, 1006),
+        (ResourceKind::Texture, 1889),
+        (ResourceKind::TextureMeta, 54),
+    ];
+    for (kind, count) in counts {
+        assert_eq!(manifest.resources[&kind].len(), count, "{:?}", kind);
+    }
+
+    let json = serde_json::to_string(&manifest).unwrap();
+    assert_eq!(serde_json::from_str::<Manifest>(&json).unwrap(), manifest);
+}
+
+#[test]
+fn enumerate_all_models() {
+    let assets = get_asset_pack("1.14");
+
+    let models = assets.enumerate_all_models("minecraft").unwrap();
+    assert_eq!(models.len(), 1201 + 1006);
+    assert!(models.iter().all(|(kind, id)| id.kind() == *kind));
+
+    let block = ResourceIdentifier::block_model("minecraft:block/stone");
+    let item = ResourceIdentifier::item_model("minecraft:item/stone");
+    assert!(models.contains(&(ResourceKind::BlockModel, block)));
+    assert!(models.contains(&(ResourceKind::ItemModel, item)));
+
+    for (kind, id) in models.iter().step_by(100) {
+        assert!(assets.contains(id), "{:?} {}", kind, id);
+    }
+}
+
+#[test]
+fn model_resolver_resolve() {
+    let assets = get_asset_pack("1.14");
+    let load_model = |id: &ResourceIdentifier| assets.load_block_model(id.as_str());
+
+    let resolved =
+        ModelResolver::resolve(&ResourceIdentifier::block_model("cube_all"), load_model).unwrap();
+    assert_eq!(
+        resolved.chain,
+        assets.load_block_model_recursive("cube_all").unwrap()
+    );
+    assert_eq!(resolved.chain.len(), 3);
+    assert_eq!(resolved.textures.get("particle"), Some("#all"));
+    assert_eq!(resolved.textures.get("north"), Some("#all"));
+
+    let resolved =
+        ModelResolver::resolve(&ResourceIdentifier::block_model("stone"), load_model).unwrap();
+    assert_eq!(resolved.chain.len(), 4);
+    assert_eq!(resolved.textures.get("particle"), Some("block/stone"));
+    assert_eq!(resolved.textures.get("north"), Some("block/stone"));
+}
+
+#[test]
+fn item_model_layers() {
+    let assets = get_asset_pack("1.14");
+
+    let models = assets.load_item_model_recursive("leather_helmet").unwrap();
+    assert_eq!(models[1].parent.as_deref(), Some("builtin/generated"));
+
+    let textures = models[0].textures.as_ref().unwrap();
+    assert_eq!(
+        textures.layers(),
+        ["item/leather_helmet", "item/leather_helmet_overlay"]
+    );
+
+    let textures = ModelResolver::resolve_textures(models.iter());
+    assert_eq!(
+        textures.layers(),
+        ["item/leather_helmet", "item/leather_helmet_overlay"]
+    );
+
+    let models = assets.load_item_model_recursive("stick").unwrap();
+    let textures = ModelResolver::resolve_textures(models.iter());
+    assert_eq!(textures.layers(), ["item/stick"]);
+}
@@ -1,11 +1,15 @@
 #![cfg(feature = "tests")]
 
 use assert_matches::assert_matches;
-use maplit::hashmap;
-
-use minecraft_assets::schemas::blockstates::{
-    multipart::{Case, Condition, WhenClause},
-    BlockStates, ModelProperties, Variant,
+use maplit::{btreemap, hashmap};
+
+use minecraft_assets::{
+    api::{ModelIdentifier, ResourceKind},
+    schemas::blockstates::{
+        multipart::{Case, Condition, WhenClause},
+        BlockStates, ModelProperties, Variant,
+    },
+    versions,
 };
 
 mod common;
@@ -28,11 +32,47 @@ macro_rules! condition {
     }
 }
 
+#[test]
+fn model_reference_matches_model_path() {
+    for version in [
+        Versions::PreFlattening,
+        Versions::PostFlattening,
+        Versions::Post_1_16_2,
+    ] {
+        for model in ["oak_planks", "cube_all"] {
+            let expected = model_path(model, version);
+
+            assert_eq!(
+                versions::model_reference(model, ResourceKind::BlockModel, version.version()),
+                expected
+            );
+            assert_eq!(
+                ModelIdentifier::to_versioned(model, ResourceKind::BlockModel, version.version()),
+                expected
+            );
+        }
+    }
+}
+
+#[test]
+fn single_variant_key_matches_single_variant_name() {
+    for version in [
+        Versions::PreFlattening,
+        Versions::PostFlattening,
+        Versions::Post_1_16_2,
+    ] {
+        assert_eq!(
+            version.version().single_variant_key(),
+            single_variant_name(version)
+        );
+    }
+}
+
 fn do_single_variant_test(bytes: &[u8], version: Versions) {
     let variant_name = single_variant_name(version);
 
     let expected = BlockStates::Variants {
-        variants: hashmap! {
+        variants: btreemap! {
             variant_name => Variant::Single(ModelProperties {
                 model: model_path("oak_planks", version),
                 ..Default::default()
@@ -129,7 +169,7 @@ fn do_single_variant_multiple_models_test(bytes: &[u8], version: Versions) {
     let actual: BlockStates = serde_json::from_slice(bytes).unwrap();
 
     let expected = BlockStates::Variants {
-        variants: hashmap! {
+        variants: btreemap! {
             single_variant_name(version) => Variant::Multiple(vec![
                 ModelProperties {
                     model: model_path("stone", version),
@@ -154,6 +194,9 @@ fn do_single_variant_multiple_models_test(bytes: &[u8], version: Versions) {
     };
 
     assert_eq!(actual, expected);
+
+    let variant = &actual.variants().unwrap()[&single_variant_name(version)];
+    assert_eq!(variant.distinct_rotations(), vec![(0, 0), (0, 180)]);
 }
 
 #[test]
@@ -244,7 +287,7 @@ fn multiple_variants() {
     .unwrap();
 
     let expected = BlockStates::Variants {
-        variants: hashmap! {
+        variants: btreemap! {
             String::from("powered=false") => Variant::Single(ModelProperties {
                 model: String::from("block/stone_pressure_plate"),
                 ..Default::default()
@@ -260,6 +303,20 @@ fn multiple_variants() {
     assert_eq!(actual, expected);
 }
 
+#[test]
+fn multiple_variants_serialize_sorted() {
+    let blockstates: BlockStates = serde_json::from_slice(include_bytes!(
+        "./assets-1.14/assets/minecraft/blockstates/stone_pressure_plate.json"
+    ))
+    .unwrap();
+
+    let serialized = serde_json::to_string(&blockstates).unwrap();
+
+    let false_index = serialized.find("\"powered=false\"").unwrap();
+    let true_index = serialized.find("\"powered=true\"").unwrap();
+    assert!(false_index < true_index);
+}
+
 fn do_multipart_test(bytes: &[u8], version: Versions) {
     let actual: BlockStates = serde_json::from_slice(bytes).unwrap();
 
@@ -423,6 +480,61 @@ fn multipart_with_or() {
     );
 }
 
+#[test]
+fn multipart_active_models() {
+    let blockstates: BlockStates = serde_json::from_slice(include_bytes!(
+        "./assets-1.14/assets/minecraft/blockstates/redstone_wire.json"
+    ))
+    .unwrap();
+
+    let active_models = |state: &[(&str, &str)]| -> Vec<String> {
+        let state = state
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect();
+
+        blockstates
+            .active_models(&state)
+            .into_iter()
+            .map(|model| model.model.clone())
+            .collect()
+    };
+
+    // A lone piece of dust is just a dot.
+    let models = active_models(&[
+        ("north", "none"),
+        ("east", "none"),
+        ("south", "none"),
+        ("west", "none"),
+        ("power", "0"),
+    ]);
+    assert_eq!(models, vec![String::from("block/redstone_dust_dot")]);
+
+    // A corner matches the second alternative of the `OR` clause for the dot,
+    // and `side|up` matches both `side` and `up`.
+    let models = active_models(&[
+        ("north", "up"),
+        ("east", "side"),
+        ("south", "none"),
+        ("west", "none"),
+        ("power", "0"),
+    ]);
+    assert!(models.contains(&String::from("block/redstone_dust_dot")));
+    assert!(models.contains(&String::from("block/redstone_dust_side0")));
+    assert!(models.contains(&String::from("block/redstone_dust_up")));
+
+    // A straight line does not have a dot.
+    let models = active_models(&[
+        ("north", "side"),
+        ("east", "none"),
+        ("south", "side"),
+        ("west", "none"),
+        ("power", "0"),
+    ]);
+    assert!(!models.contains(&String::from("block/redstone_dust_dot")));
+    assert!(!models.contains(&String::from("block/redstone_dust_up")));
+}
+
 // Some files don't have quotes around the boolean values in the "when" clauses.
 // Make sure those can parse
 #[test]
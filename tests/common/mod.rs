@@ -5,6 +5,8 @@ use std::{
 
 use serde::Deserialize;
 
+use minecraft_assets::versions::Version;
+
 pub fn get_path_relative_to_manifest_dir(
     relative_path: impl AsRef<Path>,
 ) -> Result<PathBuf, env::VarError> {
@@ -43,6 +45,16 @@ pub enum Versions {
     Post_1_16_2,
 }
 
+impl Versions {
+    pub fn version(self) -> Version {
+        match self {
+            Versions::PreFlattening => Version::V1_8,
+            Versions::PostFlattening => Version::V1_13,
+            Versions::Post_1_16_2 => Version::V1_16_2,
+        }
+    }
+}
+
 // Prior to 1.13, single-variant blockstates had "normal" as their
 // variant name. In versions >= 1.13, the variant name is ""
 pub fn single_variant_name(version: Versions) -> String {
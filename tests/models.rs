@@ -2,7 +2,10 @@
 
 use maplit::hashmap;
 
-use minecraft_assets::schemas::models::{Model, Textures};
+use minecraft_assets::{
+    api::{ResourceIdentifier, ResourceKind},
+    schemas::models::{GuiLightMode, Model, Textures},
+};
 
 mod common;
 
@@ -26,6 +29,12 @@ fn do_cube_all_test(bytes: &[u8]) {
     };
 
     assert_eq!(actual, expected);
+
+    // The textures of `cube_all` are all variables.
+    assert_eq!(
+        actual.direct_references(ResourceKind::BlockModel),
+        [ResourceIdentifier::block_model("minecraft:block/cube")]
+    );
 }
 
 #[test]
@@ -77,6 +86,41 @@ fn cube_all_1_15() {
     ));
 }
 
+#[test]
+fn gui_light_and_ambient_occlusion_1_18() {
+    let block: Model = serde_json::from_slice(include_bytes!(
+        "./assets-1.18/assets/minecraft/models/block/block.json"
+    ))
+    .unwrap();
+    assert_eq!(block.gui_light_mode, Some(GuiLightMode::Side));
+    assert_eq!(block.ambient_occlusion, None);
+    assert!(block.effective_ambient_occlusion());
+
+    let generated: Model = serde_json::from_slice(include_bytes!(
+        "./assets-1.18/assets/minecraft/models/item/generated.json"
+    ))
+    .unwrap();
+    assert_eq!(generated.gui_light_mode, Some(GuiLightMode::Front));
+    assert_eq!(generated.effective_gui_light(false), GuiLightMode::Front);
+
+    let cross: Model = serde_json::from_slice(include_bytes!(
+        "./assets-1.18/assets/minecraft/models/block/cross.json"
+    ))
+    .unwrap();
+    assert_eq!(cross.ambient_occlusion, Some(false));
+    assert!(!cross.effective_ambient_occlusion());
+}
+
+#[test]
+fn gui_light_absent_1_14() {
+    let block: Model = serde_json::from_slice(include_bytes!(
+        "./assets-1.14/assets/minecraft/models/block/block.json"
+    ))
+    .unwrap();
+    assert_eq!(block.gui_light_mode, None);
+    assert_eq!(block.effective_gui_light(false), GuiLightMode::Side);
+}
+
 fn parse_all_models_in_version(version: &str) {
     common::parse_all_in_dir::<Model>(&format!(
         "tests/assets-{}/assets/minecraft/models/block",
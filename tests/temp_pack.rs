@@ -0,0 +1,2638 @@
+//! Tests that run against small, fabricated asset packs written to a temporary
+//! directory. Unlike the other integration tests, these do not require the
+//! `minecraft-assets` submodule.
+
+use std::{
+    collections::{HashMap, HashSet},
+    fs, io,
+    path::Path,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+};
+
+use assert_matches::assert_matches;
+use tempfile::TempDir;
+
+use minecraft_assets::{
+    api::{
+        AssetPack, EnumerateResources, Error, FileSystemResourceProvider, LoadResource, Manifest,
+        ModelChainEntry, ModelResolver, ResourceCategory, ResourceIdentifier, ResourceKind,
+        ResourcePath, TextureIssue, TextureIssueKind,
+    },
+    schemas::{
+        atlas::AtlasSource, blockstates::ModelProperties, font::FontProvider, optifine::Properties,
+        recipes::Ingredient, BlockStates, Model, Recipe,
+    },
+};
+
+/// An asset pack that lives in a temporary directory for the duration of a
+/// test.
+struct TempPack {
+    dir: TempDir,
+}
+
+impl TempPack {
+    fn new() -> Self {
+        Self {
+            dir: TempDir::new().unwrap(),
+        }
+    }
+
+    /// Writes `contents` to the file at `path` relative to the pack root,
+    /// creating parent directories as needed.
+    fn write(&self, path: impl AsRef<Path>, contents: &str) -> &Self {
+        let path = self.dir.path().join(path);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, contents).unwrap();
+        self
+    }
+
+    /// Like [`write()`][Self::write], but for binary contents.
+    fn write_bytes(&self, path: impl AsRef<Path>, contents: &[u8]) -> &Self {
+        let path = self.dir.path().join(path);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, contents).unwrap();
+        self
+    }
+
+    fn asset_pack(&self) -> AssetPack {
+        AssetPack::at_path(self.dir.path())
+    }
+
+    /// Returns a provider for this pack that records every resource it loads.
+    fn counting_provider(&self) -> CountingProvider {
+        CountingProvider {
+            inner: FileSystemResourceProvider::new(self.dir.path()),
+            loads: Default::default(),
+            enumerations: Default::default(),
+        }
+    }
+}
+
+/// A provider that counts how many times each resource is loaded from the
+/// underlying file system, and how many times a directory is enumerated.
+struct CountingProvider {
+    inner: FileSystemResourceProvider,
+    loads: Arc<Mutex<HashMap<String, usize>>>,
+    enumerations: Arc<AtomicUsize>,
+}
+
+impl CountingProvider {
+    /// Returns a handle to the load counts that outlives moving the provider
+    /// into an [`AssetPack`].
+    fn loads(&self) -> Arc<Mutex<HashMap<String, usize>>> {
+        Arc::clone(&self.loads)
+    }
+
+    /// Returns a handle to the enumeration count.
+    fn enumerations(&self) -> Arc<AtomicUsize> {
+        Arc::clone(&self.enumerations)
+    }
+}
+
+impl EnumerateResources for CountingProvider {
+    fn enumerate_resources(
+        &self,
+        namespace: &str,
+        kind: ResourceKind,
+    ) -> Result<Vec<ResourceIdentifier<'static>>, io::Error> {
+        self.enumerations.fetch_add(1, Ordering::SeqCst);
+        self.inner.enumerate_resources(namespace, kind)
+    }
+
+    fn enumerate_namespaces(&self, category: ResourceCategory) -> Result<Vec<String>, io::Error> {
+        self.inner.enumerate_namespaces(category)
+    }
+}
+
+impl LoadResource for CountingProvider {
+    fn load_resource(&self, id: &ResourceIdentifier) -> Result<Vec<u8>, io::Error> {
+        let key = format!("{:?}", id.to_canonical());
+        *self.loads.lock().unwrap().entry(key).or_default() += 1;
+        self.inner.load_resource(id)
+    }
+
+    fn contains(&self, id: &ResourceIdentifier) -> bool {
+        self.inner.contains(id)
+    }
+}
+
+fn total_loads(loads: &Mutex<HashMap<String, usize>>) -> usize {
+    loads.lock().unwrap().values().sum()
+}
+
+/// A pack with a model named `shared` in both `models/block/` and
+/// `models/item/`, plus one model that only exists in each directory.
+fn same_named_models_pack() -> TempPack {
+    let pack = TempPack::new();
+    pack.write(
+        "assets/minecraft/models/block/shared.json",
+        r#"{ "parent": "block/from_block_dir" }"#,
+    )
+    .write(
+        "assets/minecraft/models/item/shared.json",
+        r#"{ "parent": "item/from_item_dir" }"#,
+    )
+    .write(
+        "assets/minecraft/models/block/block_only.json",
+        r#"{ "parent": "block/cube" }"#,
+    )
+    .write(
+        "assets/minecraft/models/item/item_only.json",
+        r#"{ "parent": "item/generated" }"#,
+    );
+    pack
+}
+
+#[test]
+fn load_block_model_reads_block_directory() {
+    let pack = same_named_models_pack();
+    let assets = pack.asset_pack();
+
+    for id in ["shared", "block/shared", "minecraft:block/shared"] {
+        let model = assets.load_block_model(id).unwrap();
+        assert_eq!(model.parent.as_deref(), Some("block/from_block_dir"));
+    }
+}
+
+#[test]
+fn load_item_model_reads_item_directory() {
+    let pack = same_named_models_pack();
+    let assets = pack.asset_pack();
+
+    for id in ["shared", "item/shared", "minecraft:item/shared"] {
+        let model = assets.load_item_model(id).unwrap();
+        assert_eq!(model.parent.as_deref(), Some("item/from_item_dir"));
+    }
+}
+
+#[test]
+fn load_block_model_does_not_fall_back_to_item_directory() {
+    let pack = same_named_models_pack();
+    let assets = pack.asset_pack();
+
+    assert_matches!(assets.load_block_model("item_only"), Err(_));
+    assert_matches!(assets.load_block_model("item/item_only"), Err(_));
+}
+
+#[test]
+fn load_item_model_does_not_fall_back_to_block_directory() {
+    let pack = same_named_models_pack();
+    let assets = pack.asset_pack();
+
+    assert_matches!(assets.load_item_model("block_only"), Err(_));
+    assert_matches!(assets.load_item_model("block/block_only"), Err(_));
+}
+
+fn oak_log_pack() -> TempPack {
+    let pack = TempPack::new();
+    pack.write(
+        "assets/minecraft/blockstates/oak_log.json",
+        r#"{
+            "variants": {
+                "axis=x": { "model": "block/oak_log_horizontal", "x": 90, "y": 90 },
+                "axis=y": { "model": "block/oak_log" },
+                "axis=z": { "model": "block/oak_log_horizontal", "x": 90 }
+            }
+        }"#,
+    )
+    .write(
+        "assets/minecraft/models/block/oak_log.json",
+        r#"{
+            "parent": "block/cube_column",
+            "textures": { "end": "block/oak_log_top", "side": "block/oak_log" }
+        }"#,
+    )
+    .write(
+        "assets/minecraft/models/block/oak_log_horizontal.json",
+        r#"{
+            "parent": "block/cube_column",
+            "textures": { "end": "block/oak_log_top", "side": "block/oak_log" }
+        }"#,
+    )
+    .write(
+        "assets/minecraft/models/block/cube_column.json",
+        r##"{
+            "textures": { "particle": "#side" },
+            "elements": [
+                {
+                    "from": [0, 0, 0],
+                    "to": [16, 16, 16],
+                    "faces": {
+                        "up": { "texture": "#end" },
+                        "north": { "texture": "#side" }
+                    }
+                }
+            ]
+        }"##,
+    );
+    pack
+}
+
+#[test]
+fn resolve_block() {
+    let pack = oak_log_pack();
+    let assets = pack.asset_pack();
+
+    let state = BlockStates::parse_variant_key("axis=y");
+    let models = assets.resolve_block("oak_log", &state).unwrap();
+
+    assert_eq!(models.len(), 1);
+    let model = &models[0].model;
+    assert_eq!(model.parent, None);
+
+    let textures = model.textures.as_ref().unwrap();
+    assert_eq!(textures["particle"].0, "block/oak_log");
+
+    let faces = &model.elements.as_ref().unwrap()[0].faces;
+    let textures: Vec<&str> = faces.values().map(|face| face.texture.0.as_str()).collect();
+    assert!(textures.contains(&"block/oak_log_top"));
+    assert!(textures.contains(&"block/oak_log"));
+
+    let state = BlockStates::parse_variant_key("axis=w");
+    assert!(assets.resolve_block("oak_log", &state).unwrap().is_empty());
+}
+
+#[test]
+fn resolve_block_weighted_variant() {
+    let pack = TempPack::new();
+    pack.write(
+        "assets/minecraft/blockstates/stone.json",
+        r#"{
+            "variants": {
+                "": [
+                    { "model": "block/stone", "weight": 1 },
+                    { "model": "block/stone_mirrored", "weight": 10 },
+                    { "model": "block/stone", "y": 90, "weight": 10 }
+                ]
+            }
+        }"#,
+    );
+    for model in ["stone", "stone_mirrored"] {
+        pack.write(
+            format!("assets/minecraft/models/block/{}.json", model),
+            &format!(r#"{{ "textures": {{ "all": "block/{}" }} }}"#, model),
+        );
+    }
+    let assets = pack.asset_pack();
+
+    // The first of the heaviest models is used.
+    let state = BlockStates::parse_variant_key("");
+    let models = assets.resolve_block("stone", &state).unwrap();
+    assert_eq!(models.len(), 1);
+    assert_eq!(models[0].y, 0);
+    assert_eq!(
+        models[0].model,
+        assets.load_block_model_resolved("stone_mirrored").unwrap()
+    );
+}
+
+#[test]
+fn resolve_block_multipart() {
+    let pack = TempPack::new();
+    let mut cases = vec![String::from(
+        r#"{ "when": { "up": "true" }, "apply": { "model": "block/cobblestone_wall_post" } }"#,
+    )];
+    for (height, model) in [("low", "side"), ("tall", "side_tall")] {
+        for (direction, y) in [("north", 0), ("east", 90), ("south", 180), ("west", 270)] {
+            cases.push(format!(
+                r#"{{
+                    "when": {{ "{}": "{}" }},
+                    "apply": {{ "model": "block/cobblestone_wall_{}", "y": {}, "uvlock": true }}
+                }}"#,
+                direction, height, model, y
+            ));
+        }
+    }
+    pack.write(
+        "assets/minecraft/blockstates/cobblestone_wall.json",
+        &format!(r#"{{ "multipart": [{}] }}"#, cases.join(",")),
+    );
+    for model in ["post", "side", "side_tall"] {
+        pack.write(
+            format!(
+                "assets/minecraft/models/block/cobblestone_wall_{}.json",
+                model
+            ),
+            &format!(
+                r#"{{ "textures": {{ "wall": "block/cobblestone_{}" }} }}"#,
+                model
+            ),
+        );
+    }
+    let assets = pack.asset_pack();
+
+    let state = BlockStates::parse_variant_key("east=low,north=none,south=tall,up=true,west=none");
+    let models = assets.resolve_block("cobblestone_wall", &state).unwrap();
+
+    let placed: Vec<_> = models
+        .iter()
+        .map(|placed| {
+            let textures = placed.model.textures.as_ref().unwrap();
+            (
+                textures["wall"].0.as_str(),
+                placed.x,
+                placed.y,
+                placed.uv_lock,
+            )
+        })
+        .collect();
+    assert_eq!(
+        placed,
+        [
+            ("block/cobblestone_post", 0, 0, false),
+            ("block/cobblestone_side", 0, 90, true),
+            ("block/cobblestone_side_tall", 0, 180, true),
+        ]
+    );
+}
+
+#[test]
+fn load_block_model_resolved() {
+    let pack = oak_log_pack();
+    let assets = pack.asset_pack();
+
+    let model = assets.load_block_model_resolved("oak_log").unwrap();
+    let column = assets.load_block_model("cube_column").unwrap();
+
+    assert_eq!(model.parent, None);
+    assert_eq!(
+        model.elements.as_ref().unwrap().len(),
+        column.elements.as_ref().unwrap().len()
+    );
+
+    let textures = model.textures.as_ref().unwrap();
+    assert_eq!(textures["end"].0, "block/oak_log_top");
+    assert_eq!(textures["side"].0, "block/oak_log");
+    assert_eq!(textures["particle"].0, "block/oak_log");
+}
+
+#[test]
+fn place_model() {
+    let pack = oak_log_pack();
+    let assets = pack.asset_pack();
+
+    let props = ModelProperties {
+        model: String::from("block/oak_log_horizontal"),
+        y: 180,
+        uv_lock: true,
+        ..Default::default()
+    };
+    let placed = assets.place_model(&props).unwrap();
+
+    assert_eq!(placed.x, 0);
+    assert_eq!(placed.y, 180);
+    assert!(placed.uv_lock);
+    assert_eq!(
+        placed.model,
+        assets
+            .load_block_model_resolved("oak_log_horizontal")
+            .unwrap()
+    );
+
+    // Placement straight from a block states file.
+    let states = assets.load_blockstates("oak_log").unwrap();
+    let props = &states.variants().unwrap()["axis=x"].models()[0];
+    let placed = assets.place_model(props).unwrap();
+
+    assert_eq!((placed.x, placed.y), (90, 90));
+    assert!(!placed.uv_lock);
+    assert_eq!(placed.model.parent, None);
+}
+
+#[test]
+fn resolve_block_cache_hit() {
+    let pack = oak_log_pack();
+    let provider = pack.counting_provider();
+    let loads = provider.loads();
+    let assets = AssetPack::new(provider).with_resolve_cache(16);
+
+    let state = BlockStates::parse_variant_key("axis=y");
+
+    let first = assets.resolve_block("oak_log", &state).unwrap();
+    let loads_after_first = total_loads(&loads);
+    assert_eq!(
+        loads.lock().unwrap()["BlockStates(\"minecraft:oak_log\")"],
+        1
+    );
+
+    // Same state under a different spelling of the block id.
+    let second = assets.resolve_block("minecraft:oak_log", &state).unwrap();
+    assert_eq!(first, second);
+    assert_eq!(total_loads(&loads), loads_after_first);
+    assert_eq!(
+        loads.lock().unwrap()["BlockStates(\"minecraft:oak_log\")"],
+        1
+    );
+
+    // A different state is a cache miss.
+    let state = BlockStates::parse_variant_key("axis=x");
+    assets.resolve_block("oak_log", &state).unwrap();
+    assert_eq!(
+        loads.lock().unwrap()["BlockStates(\"minecraft:oak_log\")"],
+        2
+    );
+
+    // Invalidating the cache forces a reload.
+    assets.invalidate_resolve_cache();
+    let state = BlockStates::parse_variant_key("axis=y");
+    assets.resolve_block("oak_log", &state).unwrap();
+    assert_eq!(
+        loads.lock().unwrap()["BlockStates(\"minecraft:oak_log\")"],
+        3
+    );
+}
+
+#[test]
+fn enumeration_cache() {
+    let pack = oak_log_pack();
+    let provider = pack.counting_provider();
+    let enumerations = provider.enumerations();
+    let assets = AssetPack::new(provider).with_enumeration_cache();
+
+    let first = assets
+        .enumerate_resources("minecraft", ResourceKind::BlockModel)
+        .unwrap();
+    assert_eq!(first.len(), 3);
+    assert_eq!(enumerations.load(Ordering::SeqCst), 1);
+
+    // Files added after the first enumeration are not seen until the cache is
+    // invalidated.
+    pack.write("assets/minecraft/models/block/stone.json", "{}");
+
+    let second = assets
+        .enumerate_resources("minecraft", ResourceKind::BlockModel)
+        .unwrap();
+    assert_eq!(first, second);
+    assert_eq!(enumerations.load(Ordering::SeqCst), 1);
+
+    // A different kind is a cache miss.
+    assets
+        .enumerate_resources("minecraft", ResourceKind::BlockStates)
+        .unwrap();
+    assert_eq!(enumerations.load(Ordering::SeqCst), 2);
+
+    assets.invalidate_enumeration();
+    let third = assets
+        .enumerate_resources("minecraft", ResourceKind::BlockModel)
+        .unwrap();
+    assert_eq!(third.len(), 4);
+    assert_eq!(enumerations.load(Ordering::SeqCst), 3);
+}
+
+#[test]
+fn enumeration_without_cache() {
+    let pack = oak_log_pack();
+    let provider = pack.counting_provider();
+    let enumerations = provider.enumerations();
+    let assets = AssetPack::new(provider);
+
+    for _ in 0..2 {
+        assets
+            .enumerate_resources("minecraft", ResourceKind::BlockModel)
+            .unwrap();
+    }
+    assert_eq!(enumerations.load(Ordering::SeqCst), 2);
+}
+
+#[test]
+fn iter_resources() {
+    let pack = oak_log_pack();
+    pack.write("assets/minecraft/models/block/_template.json", "{}")
+        .write("assets/minecraft/models/block/nested/thing.json", "{}");
+    let assets = pack.asset_pack();
+
+    let mut resources: Vec<_> = assets
+        .iter_resources("minecraft", ResourceKind::BlockModel)
+        .unwrap()
+        .collect();
+    resources.sort_by(|a, b| a.0.as_str().cmp(b.0.as_str()));
+
+    let names: Vec<&str> = resources.iter().map(|(id, _)| id.as_str()).collect();
+    assert_eq!(
+        names,
+        [
+            "cube_column",
+            "nested/thing",
+            "oak_log",
+            "oak_log_horizontal"
+        ]
+    );
+
+    for (id, path) in &resources {
+        assert!(path.is_file(), "{:?} does not exist", path);
+        assert!(path.ends_with(format!("models/block/{}.json", id.as_str())));
+    }
+
+    // Not available without a root directory.
+    let assets = AssetPack::new(pack.counting_provider());
+    let err = assets
+        .iter_resources("minecraft", ResourceKind::BlockModel)
+        .err()
+        .unwrap();
+    assert_matches!(err, Error::IoError(err) if err.kind() == io::ErrorKind::Unsupported);
+}
+
+#[test]
+fn resource_identifier_from_path() {
+    let pack = oak_log_pack();
+    pack.write("assets/minecraft/textures/block/oak_log.png", "")
+        .write("assets/minecraft/textures/block/oak_log.png.mcmeta", "{}")
+        .write("assets/minecraft/models/item/oak_log.json", "{}")
+        .write("assets/foo/models/block/nested/thing.json", "{}")
+        .write("data/minecraft/tags/blocks/logs.json", "{}");
+    let root = pack.dir.path();
+    let assets = pack.asset_pack();
+
+    let kinds = [
+        ResourceKind::BlockStates,
+        ResourceKind::BlockModel,
+        ResourceKind::ItemModel,
+        ResourceKind::Texture,
+        ResourceKind::TextureMeta,
+        ResourceKind::Tag,
+    ];
+    for namespace in ["minecraft", "foo"] {
+        for kind in kinds {
+            let resources = match assets.iter_resources(namespace, kind) {
+                Ok(resources) => resources,
+                Err(_) => continue,
+            };
+
+            for (_, path) in resources {
+                let id = ResourceIdentifier::from_path(root, &path).unwrap();
+                assert_eq!(id.kind(), kind);
+                assert_eq!(id.namespace(), namespace);
+                assert_eq!(*ResourcePath::for_resource(root, &id), path);
+            }
+        }
+    }
+
+    let id =
+        ResourceIdentifier::from_path(root, root.join("assets/foo/models/block/nested/thing.json"));
+    assert_eq!(id.unwrap().as_str(), "foo:block/nested/thing");
+
+    for path in [
+        "assets/minecraft/blockstates",
+        "assets/minecraft/oak_log.json",
+        "assets/minecraft/blockstates/oak_log.png",
+        "assets/minecraft/textures/.png",
+        "data/minecraft/blockstates/oak_log.json",
+        "stuff/minecraft/blockstates/oak_log.json",
+    ] {
+        assert_eq!(
+            ResourceIdentifier::from_path(root, root.join(path)),
+            None,
+            "{}",
+            path
+        );
+    }
+}
+
+#[test]
+fn load_all_models() {
+    let pack = TempPack::new();
+    pack.write(
+        "assets/foo/models/block/good_a.json",
+        r#"{ "parent": "block/cube" }"#,
+    )
+    .write("assets/foo/models/block/good_b.json", "{}")
+    .write("assets/foo/models/block/broken.json", r#"{ "parent": "#)
+    .write("assets/foo/models/item/item.json", "{}");
+    let assets = pack.asset_pack();
+
+    let mut models = assets
+        .load_all_models("foo", ResourceKind::BlockModel)
+        .unwrap();
+    models.sort_by(|a, b| a.0.as_str().cmp(b.0.as_str()));
+
+    let ids: Vec<&str> = models.iter().map(|(id, _)| id.as_str()).collect();
+    assert_eq!(
+        ids,
+        ["foo:block/broken", "foo:block/good_a", "foo:block/good_b"]
+    );
+
+    assert_matches!(models[0].1, Err(Error::Parse { .. }));
+    assert_eq!(
+        models[1].1.as_ref().unwrap().parent.as_deref(),
+        Some("block/cube")
+    );
+    assert_matches!(models[2].1, Ok(_));
+
+    // A namespace without models has none to load, but a kind that is not a
+    // model kind fails the whole call.
+    assert!(assets
+        .load_all_models("bar", ResourceKind::BlockModel)
+        .unwrap()
+        .is_empty());
+    assert_matches!(
+        assets.load_all_models("foo", ResourceKind::BlockStates),
+        Err(Error::IoError(_))
+    );
+}
+
+#[test]
+fn load_item_model_recursive_with_builtin() {
+    let pack = TempPack::new();
+    pack.write(
+        "assets/minecraft/models/item/stick.json",
+        r#"{ "parent": "item/handheld", "textures": { "layer0": "item/stick" } }"#,
+    )
+    .write(
+        "assets/minecraft/models/item/handheld.json",
+        r#"{ "parent": "item/generated" }"#,
+    )
+    .write(
+        "assets/minecraft/models/item/generated.json",
+        r#"{ "parent": "builtin/generated" }"#,
+    )
+    .write(
+        "assets/minecraft/models/item/chest.json",
+        r#"{ "textures": { "particle": "block/oak_planks" } }"#,
+    );
+    let assets = pack.asset_pack();
+
+    let chain = assets
+        .load_item_model_recursive_with_builtin("stick")
+        .unwrap();
+    assert_eq!(chain.len(), 4);
+    assert!(chain[..3].iter().all(|entry| entry.as_model().is_some()));
+    assert_eq!(
+        chain[3],
+        ModelChainEntry::Builtin(String::from("generated"))
+    );
+    assert_eq!(chain[3].builtin_name(), Some("generated"));
+
+    // No builtin at the top of the chain.
+    let chain = assets
+        .load_item_model_recursive_with_builtin("chest")
+        .unwrap();
+    assert_eq!(chain.len(), 1);
+    assert!(chain[0].as_model().is_some());
+}
+
+#[test]
+fn load_recipe() {
+    let pack = TempPack::new();
+    pack.write(
+        "data/minecraft/recipes/iron_ingot_from_smelting.json",
+        r#"{
+            "type": "minecraft:smelting",
+            "group": "iron_ingot",
+            "ingredient": [
+                { "item": "minecraft:iron_ore" },
+                { "item": "minecraft:deepslate_iron_ore" }
+            ],
+            "result": "minecraft:iron_ingot",
+            "experience": 0.7
+        }"#,
+    )
+    .write(
+        "data/minecraft/recipes/firework_rocket.json",
+        r#"{ "type": "minecraft:crafting_special_firework_rocket" }"#,
+    );
+    let assets = pack.asset_pack();
+
+    let recipe = assets.load_recipe("iron_ingot_from_smelting").unwrap();
+    assert_matches!(recipe, Recipe::Smelting(ref smelting) => {
+        assert_eq!(smelting.result, "minecraft:iron_ingot");
+        assert_matches!(smelting.ingredient, Ingredient::Multiple(_));
+        assert_eq!(smelting.ingredient.choices().len(), 2);
+    });
+
+    let recipe = assets.load_recipe("minecraft:firework_rocket").unwrap();
+    assert_eq!(recipe, Recipe::Other);
+
+    let ids = assets
+        .enumerate_resources("minecraft", ResourceKind::Recipe)
+        .unwrap();
+    assert_eq!(ids.len(), 2);
+
+    // Recipes are not read from `assets/`.
+    assert_matches!(assets.load_blockstates("iron_ingot_from_smelting"), Err(_));
+}
+
+#[test]
+fn load_tag() {
+    let pack = TempPack::new();
+    pack.write(
+        "data/minecraft/tags/blocks/logs.json",
+        r##"{ "values": ["#minecraft:oak_logs", "minecraft:crimson_stem"] }"##,
+    )
+    .write(
+        "data/minecraft/tags/blocks/oak_logs.json",
+        r#"{ "replace": true, "values": ["minecraft:oak_log"] }"#,
+    )
+    .write(
+        "data/minecraft/tags/items/logs.json",
+        r#"{ "values": [{ "id": "minecraft:oak_log", "required": false }] }"#,
+    );
+    let assets = pack.asset_pack();
+
+    let tag = assets.load_tag("blocks", "logs").unwrap();
+    assert!(!tag.replace);
+    assert!(tag.values[0].is_tag_reference());
+    assert!(!tag.values[1].is_tag_reference());
+
+    // Follow the reference to the other tag.
+    let tag = assets.load_tag("blocks", tag.values[0].id()).unwrap();
+    assert!(tag.replace);
+    assert_eq!(tag.values[0].id(), "minecraft:oak_log");
+
+    let tag = assets.load_tag("items", "minecraft:logs").unwrap();
+    assert!(!tag.values[0].is_required());
+
+    assert_matches!(assets.load_tag("items", "oak_logs"), Err(_));
+
+    let ids = assets
+        .enumerate_resources("minecraft", ResourceKind::Tag)
+        .unwrap();
+    assert_eq!(ids.len(), 3);
+    assert!(ids.iter().any(|id| id.path() == "blocks/oak_logs"));
+}
+
+#[test]
+fn load_font() {
+    let pack = TempPack::new();
+    pack.write(
+        "assets/minecraft/font/default.json",
+        r#"{
+            "providers": [
+                { "type": "space", "advances": { " ": 4, "\u200c": 0 } },
+                {
+                    "type": "bitmap",
+                    "file": "minecraft:font/ascii.png",
+                    "ascent": 7,
+                    "chars": ["\u0000AB", "ab"]
+                },
+                {
+                    "type": "ttf",
+                    "file": "minecraft:mono.ttf",
+                    "shift": [0.5, 1.0],
+                    "size": 9.0,
+                    "oversample": 2.0,
+                    "skip": "ab"
+                },
+                {
+                    "type": "legacy_unicode",
+                    "sizes": "minecraft:font/glyph_sizes.bin",
+                    "template": "minecraft:font/unicode_page_%s.png"
+                }
+            ]
+        }"#,
+    );
+    let assets = pack.asset_pack();
+
+    let font = assets.load_font("minecraft", "default").unwrap();
+    assert_eq!(font.providers.len(), 4);
+
+    assert_matches!(
+        font.providers[1],
+        FontProvider::Bitmap {
+            height: 8,
+            ascent: 7,
+            ..
+        }
+    );
+    assert_matches!(
+        font.providers[2],
+        FontProvider::Ttf { size, oversample, shift, .. } => {
+            assert_eq!(size, 9.0);
+            assert_eq!(oversample, 2.0);
+            assert_eq!(shift, [0.5, 1.0]);
+        }
+    );
+
+    assert_eq!(font.provider_for_char(' '), Some(&font.providers[0]));
+    assert_eq!(font.provider_for_char('A'), Some(&font.providers[1]));
+    assert_eq!(font.provider_for_char('\0'), Some(&font.providers[2]));
+    assert_eq!(font.provider_for_char('z'), Some(&font.providers[2]));
+
+    assert_matches!(assets.load_font("minecraft", "alt"), Err(_));
+}
+
+#[cfg(feature = "zip")]
+#[test]
+fn from_zip() {
+    use std::io::Write;
+
+    let dir = TempDir::new().unwrap();
+    let zip_path = dir.path().join("pack.zip");
+
+    let mut writer = zip::ZipWriter::new(fs::File::create(&zip_path).unwrap());
+    for (name, contents) in [
+        (
+            "assets/minecraft/blockstates/stone.json",
+            r#"{ "variants": { "": { "model": "block/stone" } } }"#,
+        ),
+        ("assets/minecraft/blockstates/_ignored.json", "{}"),
+        ("assets/minecraft/models/block/stone.json", "{}"),
+        ("pack.mcmeta", "{}"),
+    ] {
+        writer
+            .start_file(name, zip::write::FileOptions::default())
+            .unwrap();
+        writer.write_all(contents.as_bytes()).unwrap();
+    }
+    writer.finish().unwrap();
+
+    let assets = AssetPack::from_zip(&zip_path).unwrap();
+
+    let states = assets.load_blockstates("minecraft:stone").unwrap();
+    assert_eq!(
+        states.variants().unwrap()[""].models()[0].model,
+        "block/stone"
+    );
+
+    let ids = assets
+        .enumerate_resources("minecraft", ResourceKind::BlockStates)
+        .unwrap();
+    assert_eq!(ids, [ResourceIdentifier::blockstates("stone")]);
+    assert_eq!(
+        assets
+            .enumerate_namespaces(ResourceCategory::Assets)
+            .unwrap(),
+        ["minecraft"]
+    );
+
+    assert_matches!(
+        assets.load_blockstates("dirt"),
+        Err(Error::NotFound { location }) if location == "assets/minecraft/blockstates/dirt.json"
+    );
+
+    assert!(assets.contains(&ResourceIdentifier::block_model("stone")));
+    assert!(!assets.contains(&ResourceIdentifier::block_model("dirt")));
+
+    // Not a zip file.
+    let not_zip = dir.path().join("not_a.zip");
+    fs::write(&not_zip, "hello").unwrap();
+    assert_matches!(
+        AssetPack::from_zip(&not_zip).err(),
+        Some(Error::IoError(err)) if err.kind() == io::ErrorKind::InvalidData
+    );
+}
+
+#[cfg(feature = "zip")]
+#[test]
+fn jar_resource_provider() {
+    use std::io::Write;
+
+    use minecraft_assets::api::JarResourceProvider;
+
+    let minecraft_dir = TempDir::new().unwrap();
+    let jar_path = JarResourceProvider::version_jar_path(minecraft_dir.path(), "1.19.4");
+    assert_eq!(
+        jar_path,
+        minecraft_dir.path().join("versions/1.19.4/1.19.4.jar")
+    );
+    fs::create_dir_all(jar_path.parent().unwrap()).unwrap();
+
+    let mut writer = zip::ZipWriter::new(fs::File::create(&jar_path).unwrap());
+    for (name, contents) in [
+        ("META-INF/MANIFEST.MF", "Manifest-Version: 1.0"),
+        ("net/minecraft/client/main/Main.class", ""),
+        (
+            "assets/minecraft/blockstates/stone.json",
+            r#"{ "variants": { "": { "model": "block/stone" } } }"#,
+        ),
+        ("assets/minecraft/models/block/stone.json", "{}"),
+        (
+            "data/minecraft/tags/blocks/logs.json",
+            r#"{ "values": [] }"#,
+        ),
+        ("version.json", "{}"),
+    ] {
+        writer
+            .start_file(name, zip::write::FileOptions::default())
+            .unwrap();
+        writer.write_all(contents.as_bytes()).unwrap();
+    }
+    writer.finish().unwrap();
+
+    let provider = JarResourceProvider::open_version(minecraft_dir.path(), "1.19.4").unwrap();
+    let assets = AssetPack::new(provider);
+
+    let states = assets.load_blockstates("stone").unwrap();
+    assert_eq!(
+        states.variants().unwrap()[""].models()[0].model,
+        "block/stone"
+    );
+    assert!(assets.load_tag("blocks", "logs").unwrap().values.is_empty());
+    assert_eq!(
+        assets.enumerate_all("minecraft").unwrap(),
+        [
+            ResourceIdentifier::blockstates("minecraft:stone"),
+            ResourceIdentifier::block_model("minecraft:block/stone"),
+            ResourceIdentifier::tag("minecraft:blocks/logs"),
+        ]
+    );
+
+    assert_matches!(
+        JarResourceProvider::open_version(minecraft_dir.path(), "1.20").err(),
+        Some(err) if err.kind() == io::ErrorKind::NotFound
+    );
+}
+
+#[test]
+fn load_particle() {
+    let pack = TempPack::new();
+    pack.write(
+        "assets/minecraft/particles/explosion.json",
+        r#"{ "textures": ["minecraft:explosion_0", "minecraft:explosion_1"] }"#,
+    )
+    .write("assets/minecraft/particles/block.json", "{}");
+    let assets = pack.asset_pack();
+
+    let particle = assets.load_particle("minecraft", "explosion").unwrap();
+    assert_eq!(
+        particle.textures,
+        ["minecraft:explosion_0", "minecraft:explosion_1"]
+    );
+
+    let particle = assets.load_particle("minecraft", "block").unwrap();
+    assert!(particle.textures.is_empty());
+
+    let ids = assets
+        .enumerate_resources("minecraft", ResourceKind::Particle)
+        .unwrap();
+    assert_eq!(ids.len(), 2);
+}
+
+#[test]
+fn find_dangling_texture_refs() {
+    let pack = TempPack::new();
+    pack.write(
+        "assets/minecraft/models/block/cube_all.json",
+        r##"{ "textures": { "particle": "#all", "up": "#all", "down": "#all" } }"##,
+    )
+    .write(
+        "assets/minecraft/models/block/stone.json",
+        r#"{ "parent": "block/cube_all", "textures": { "all": "block/stone" } }"#,
+    )
+    .write(
+        "assets/minecraft/models/block/dirt.json",
+        r#"{ "parent": "block/cube_all", "textures": { "all": "block/dirt" } }"#,
+    )
+    .write(
+        "assets/minecraft/models/block/unresolved.json",
+        r#"{ "parent": "block/cube_all" }"#,
+    )
+    .write("assets/minecraft/textures/block/stone.png", "");
+    let assets = pack.asset_pack();
+
+    assert!(assets.contains(&ResourceIdentifier::texture("block/stone")));
+    assert!(!assets.contains(&ResourceIdentifier::texture("block/dirt")));
+
+    let dangling = assets.find_dangling_texture_refs("minecraft").unwrap();
+    assert_eq!(
+        dangling,
+        [(
+            ResourceIdentifier::block_model("minecraft:block/dirt"),
+            String::from("block/dirt")
+        )]
+    );
+}
+
+#[test]
+fn missing_resource_is_not_found() {
+    let pack = TempPack::new();
+    pack.write(
+        "assets/minecraft/models/block/broken.json",
+        r#"{ "parent": "#,
+    );
+    let assets = pack.asset_pack();
+
+    let expected =
+        ResourcePath::for_resource(pack.dir.path(), &ResourceIdentifier::block_model("missing"));
+    assert_matches!(
+        assets.load_block_model("missing"),
+        Err(Error::NotFound { location }) if Path::new(&location) == expected.as_path()
+    );
+
+    // A missing parent is reported the same way.
+    pack.write(
+        "assets/minecraft/models/block/orphan.json",
+        r#"{ "parent": "block/missing" }"#,
+    );
+    assert_matches!(
+        assets.load_block_model_recursive("orphan"),
+        Err(Error::NotFound { .. })
+    );
+
+    // Parse errors include the path of the file.
+    let err = assets.load_block_model("broken").unwrap_err();
+    let message = err.to_string();
+    let expected = pack
+        .dir
+        .path()
+        .join("assets/minecraft/models/block/broken.json");
+    assert_matches!(err, Error::Parse { path, .. } if path == expected);
+    assert!(message.contains(&*expected.to_string_lossy()));
+    assert!(message.contains("line 1"));
+}
+
+#[test]
+fn resource_exists() {
+    let pack = same_named_models_pack();
+    pack.write("assets/minecraft/blockstates/stone.json", "not even json");
+    let provider = pack.counting_provider();
+    let loads = provider.loads();
+    let assets = AssetPack::new(provider);
+
+    assert!(assets.has_blockstates("stone"));
+    assert!(assets.has_blockstates("minecraft:stone"));
+    assert!(!assets.has_blockstates("dirt"));
+
+    assert!(assets.has_block_model("block_only"));
+    assert!(assets.has_block_model("block/block_only"));
+    assert!(!assets.has_block_model("item_only"));
+
+    assert!(assets.resource_exists(&ResourceIdentifier::item_model("item_only")));
+    assert!(!assets.resource_exists(&ResourceIdentifier::texture("block/stone")));
+
+    // Nothing was actually loaded.
+    assert_eq!(total_loads(&loads), 0);
+}
+
+#[test]
+fn load_blockstates_bytes() {
+    let pack = oak_log_pack();
+    let assets = pack.asset_pack();
+
+    let bytes = assets.load_blockstates_bytes("oak_log").unwrap();
+    assert_eq!(
+        bytes,
+        fs::read(
+            pack.dir
+                .path()
+                .join("assets/minecraft/blockstates/oak_log.json")
+        )
+        .unwrap()
+    );
+    assert_eq!(
+        BlockStates::from_slice(&bytes).unwrap(),
+        assets.load_blockstates("oak_log").unwrap()
+    );
+
+    assert_matches!(
+        assets.load_blockstates_bytes("spruce_log"),
+        Err(Error::NotFound { .. })
+    );
+}
+
+#[test]
+fn strict_rotation_validation() {
+    let pack = TempPack::new();
+    pack.write(
+        "assets/minecraft/blockstates/crooked.json",
+        r#"{ "variants": { "": { "model": "block/crooked", "x": 90, "y": 45 } } }"#,
+    )
+    .write(
+        "assets/minecraft/blockstates/crooked_multipart.json",
+        r#"{ "multipart": [ { "apply": { "model": "block/crooked", "x": -90 } } ] }"#,
+    );
+
+    let lenient = pack.asset_pack();
+    let states = lenient.load_blockstates("crooked").unwrap();
+    assert_eq!(states.variants().unwrap()[""].models()[0].y, 45);
+
+    let strict = pack.asset_pack().strict(true);
+    assert_matches!(
+        strict.load_blockstates("crooked"),
+        Err(Error::InvalidRotation { value: 45 })
+    );
+    assert_matches!(
+        strict.load_blockstates("crooked_multipart"),
+        Err(Error::InvalidRotation { value: -90 })
+    );
+
+    // Valid rotations still load in strict mode.
+    let pack = oak_log_pack();
+    let strict = pack.asset_pack().strict(true);
+    assert_matches!(strict.load_blockstates("oak_log"), Ok(_));
+}
+
+#[test]
+fn collect_model_textures() {
+    let pack = TempPack::new();
+    pack.write(
+        "assets/minecraft/models/block/cube_column.json",
+        r##"{
+            "textures": { "particle": "#side" },
+            "elements": [
+                {
+                    "from": [0, 0, 0],
+                    "to": [16, 16, 16],
+                    "faces": {
+                        "up": { "texture": "#end" },
+                        "north": { "texture": "#side" },
+                        "south": { "texture": "#overlay" },
+                        "down": { "texture": "block/bedrock" }
+                    }
+                }
+            ]
+        }"##,
+    )
+    .write(
+        "assets/minecraft/models/block/oak_log.json",
+        r#"{
+            "parent": "block/cube_column",
+            "textures": { "end": "block/oak_log_top", "side": "minecraft:block/oak_log" }
+        }"#,
+    );
+    let assets = pack.asset_pack();
+
+    let textures = assets
+        .collect_model_textures(ResourceIdentifier::block_model("oak_log"))
+        .unwrap();
+
+    let expected: HashSet<_> = [
+        ResourceIdentifier::texture("minecraft:block/oak_log_top"),
+        ResourceIdentifier::texture("minecraft:block/oak_log"),
+        ResourceIdentifier::texture("minecraft:block/bedrock"),
+    ]
+    .into_iter()
+    .collect();
+    assert_eq!(textures, expected);
+    assert!(textures
+        .iter()
+        .all(|id| id.as_str().starts_with("minecraft:")));
+
+    assert_matches!(
+        assets.collect_model_textures(ResourceIdentifier::block_model("spruce_log")),
+        Err(Error::NotFound { .. })
+    );
+}
+
+#[test]
+fn find_cross_namespace_duplicates() {
+    let pack = TempPack::new();
+    pack.write("assets/minecraft/blockstates/stone.json", "{}")
+        .write("assets/minecraft/blockstates/dirt.json", "{}")
+        .write("assets/custom/blockstates/stone.json", "{}")
+        .write("assets/custom/blockstates/ruby_ore.json", "{}")
+        .write("assets/other/blockstates/stone.json", "{}")
+        .write("assets/textures_only/textures/block/stone.png", "")
+        .write("data/minecraft/recipes/stone.json", "{}");
+    let assets = pack.asset_pack();
+
+    assert_eq!(
+        assets
+            .enumerate_namespaces(ResourceCategory::Assets)
+            .unwrap(),
+        ["custom", "minecraft", "other", "textures_only"]
+    );
+
+    let duplicates = assets
+        .find_cross_namespace_duplicates(ResourceKind::BlockStates)
+        .unwrap();
+    assert_eq!(duplicates.len(), 1);
+    assert_eq!(duplicates["stone"], ["custom", "minecraft", "other"]);
+
+    let duplicates = assets
+        .find_cross_namespace_duplicates(ResourceKind::Recipe)
+        .unwrap();
+    assert!(duplicates.is_empty());
+}
+
+#[test]
+fn load_atlas() {
+    let pack = TempPack::new();
+    pack.write(
+        "assets/minecraft/atlases/blocks.json",
+        r#"{
+            "sources": [
+                { "type": "directory", "source": "block", "prefix": "block/" },
+                { "type": "minecraft:filter", "pattern": { "path": "block/debug.*" } },
+                {
+                    "type": "unstitch",
+                    "resource": "minecraft:gui/icons",
+                    "divisor_x": 256,
+                    "regions": [
+                        { "sprite": "heart", "x": 16, "y": 0, "width": 9, "height": 9 }
+                    ]
+                },
+                {
+                    "type": "paletted_permutations",
+                    "textures": ["trims/models/armor/coast"],
+                    "palette_key": "trims/color_palettes/trim_palette",
+                    "permutations": { "gold": "trims/color_palettes/gold" }
+                },
+                { "type": "minecraft:some_future_source" }
+            ]
+        }"#,
+    );
+    let assets = pack.asset_pack();
+
+    let atlas = assets.load_atlas("minecraft", "blocks").unwrap();
+    assert_eq!(atlas.sources.len(), 5);
+
+    assert_matches!(
+        &atlas.sources[1],
+        AtlasSource::Filter { pattern } if pattern.namespace.is_none()
+            && pattern.path.as_deref() == Some("block/debug.*")
+    );
+    assert_matches!(
+        &atlas.sources[2],
+        AtlasSource::Unstitch { divisor_x, divisor_y, regions, .. }
+            if *divisor_x == 256.0 && *divisor_y == 1.0 && regions[0].sprite == "heart"
+    );
+    assert_matches!(
+        &atlas.sources[3],
+        AtlasSource::PalettedPermutations { permutations, .. }
+            if permutations["gold"] == "trims/color_palettes/gold"
+    );
+    assert_matches!(atlas.sources[4], AtlasSource::Other);
+
+    assert_matches!(
+        assets.load_atlas("minecraft", "paintings"),
+        Err(Error::NotFound { .. })
+    );
+}
+
+#[test]
+fn load_model_recursive() {
+    let pack = TempPack::new();
+    pack.write(
+        "assets/minecraft/models/item/diamond_hoe.json",
+        r#"{ "parent": "item/handheld", "textures": { "layer0": "item/diamond_hoe" } }"#,
+    )
+    .write(
+        "assets/minecraft/models/item/handheld.json",
+        r#"{ "parent": "item/generated" }"#,
+    )
+    .write("assets/minecraft/models/item/generated.json", "{}");
+    let assets = pack.asset_pack();
+
+    let models = assets
+        .load_model_recursive(&ResourceIdentifier::item_model("diamond_hoe"))
+        .unwrap();
+    assert_eq!(models.len(), 3);
+    assert_eq!(
+        models,
+        assets.load_item_model_recursive("diamond_hoe").unwrap()
+    );
+
+    // Ids from enumeration can be passed straight through.
+    let id = assets
+        .enumerate_resources("minecraft", ResourceKind::ItemModel)
+        .unwrap()
+        .into_iter()
+        .find(|id| id.as_str() == "handheld")
+        .unwrap();
+    assert_eq!(assets.load_model_recursive(&id).unwrap().len(), 2);
+
+    assert_matches!(
+        assets.load_model_recursive(&ResourceIdentifier::blockstates("diamond_hoe")),
+        Err(Error::IoError(err)) if err.kind() == io::ErrorKind::InvalidInput
+    );
+}
+
+#[cfg(feature = "tokio")]
+#[test]
+fn async_loading() {
+    let pack = oak_log_pack();
+    let assets = pack.asset_pack();
+
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .build()
+        .unwrap();
+
+    runtime.block_on(async {
+        assert_eq!(
+            assets.load_blockstates_async("oak_log").await.unwrap(),
+            assets.load_blockstates("oak_log").unwrap()
+        );
+        assert_eq!(
+            assets
+                .load_block_model_recursive_async("oak_log")
+                .await
+                .unwrap(),
+            assets.load_block_model_recursive("oak_log").unwrap()
+        );
+        assert_matches!(
+            assets.load_block_model_async("spruce_log").await,
+            Err(Error::NotFound { .. })
+        );
+
+        let assets = AssetPack::new(pack.counting_provider());
+        assert_matches!(
+            assets.load_blockstates_async("oak_log").await,
+            Err(Error::IoError(err)) if err.kind() == io::ErrorKind::Unsupported
+        );
+    });
+}
+
+#[test]
+fn load_block_model_recursive_into() {
+    let pack = oak_log_pack();
+    pack.write(
+        "assets/minecraft/models/block/stone.json",
+        r#"{ "parent": "block/cube_all", "textures": { "all": "block/stone" } }"#,
+    )
+    .write(
+        "assets/minecraft/models/block/cube_all.json",
+        r#"{ "parent": "block/cube" }"#,
+    )
+    .write("assets/minecraft/models/block/cube.json", "{}");
+    let assets = pack.asset_pack();
+
+    let mut buf = Vec::new();
+
+    assets
+        .load_block_model_recursive_into("oak_log", &mut buf)
+        .unwrap();
+    assert_eq!(buf, assets.load_block_model_recursive("oak_log").unwrap());
+
+    let capacity = buf.capacity();
+    assets
+        .load_block_model_recursive_into("stone", &mut buf)
+        .unwrap();
+    assert_eq!(buf, assets.load_block_model_recursive("stone").unwrap());
+    assert!(buf.capacity() >= capacity);
+
+    assert_matches!(
+        assets.load_block_model_recursive_into("missing", &mut buf),
+        Err(Error::NotFound { .. })
+    );
+    assert!(buf.is_empty());
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn load_all_block_models() {
+    let pack = TempPack::new();
+    for i in 0..50 {
+        pack.write(
+            format!("assets/foo/models/block/model_{}.json", i),
+            &format!(r#"{{ "textures": {{ "all": "block/texture_{}" }} }}"#, i),
+        );
+    }
+    pack.write("assets/foo/models/block/broken.json", r#"{ "parent": "#);
+    let assets = pack.asset_pack();
+
+    let loaded = assets.load_all_block_models("foo", false).unwrap();
+    assert_eq!(loaded.models.len(), 50);
+    assert_eq!(
+        loaded.models[&ResourceIdentifier::block_model("foo:block/model_7")]
+            .textures
+            .as_ref()
+            .unwrap()
+            .get("all"),
+        Some("block/texture_7")
+    );
+
+    assert_eq!(loaded.errors.len(), 1);
+    assert_eq!(loaded.errors[0].0.as_str(), "foo:block/broken");
+    assert_matches!(loaded.errors[0].1, Error::Parse { .. });
+
+    assert_matches!(
+        assets.load_all_block_models("foo", true),
+        Err(Error::Parse { .. })
+    );
+    assert!(assets
+        .load_all_block_models("bar", false)
+        .unwrap()
+        .models
+        .is_empty());
+}
+
+#[test]
+fn model_cache() {
+    let pack = oak_log_pack();
+    pack.write(
+        "assets/minecraft/models/block/stripped_oak_log.json",
+        r#"{ "parent": "block/cube_column" }"#,
+    );
+    let provider = pack.counting_provider();
+    let loads = provider.loads();
+    let assets = AssetPack::new(provider).with_cache();
+
+    let first = assets.load_block_model_recursive("oak_log").unwrap();
+    let loads_after_first = total_loads(&loads);
+    assert_eq!(loads_after_first, first.len());
+
+    // The shared parent is not read again.
+    let second = assets
+        .load_block_model_recursive("stripped_oak_log")
+        .unwrap();
+    assert_eq!(second.len(), first.len());
+    assert_eq!(total_loads(&loads), loads_after_first + 1);
+
+    // Neither is a model that was loaded directly.
+    assert_eq!(assets.load_block_model("oak_log").unwrap(), first[0]);
+    assert_eq!(total_loads(&loads), loads_after_first + 1);
+
+    assets.clear_cache();
+    assets.load_block_model("oak_log").unwrap();
+    assert_eq!(total_loads(&loads), loads_after_first + 2);
+}
+
+#[test]
+fn export_manifest() {
+    let pack = oak_log_pack();
+    pack.write("assets/minecraft/textures/block/oak_log.png", "")
+        .write("assets/custom/blockstates/ruby_ore.json", "{}");
+    let assets = pack.asset_pack();
+
+    let manifest = assets.export_manifest("minecraft").unwrap();
+    assert_eq!(manifest.namespace, "minecraft");
+    assert_eq!(manifest.len(), 5);
+
+    let kinds: Vec<_> = manifest.resources.keys().copied().collect();
+    assert_eq!(
+        kinds,
+        [
+            ResourceKind::BlockStates,
+            ResourceKind::BlockModel,
+            ResourceKind::Texture
+        ]
+    );
+
+    let block_models: Vec<_> = manifest.resources[&ResourceKind::BlockModel]
+        .iter()
+        .map(|entry| entry.location.as_str())
+        .collect();
+    assert_eq!(
+        block_models,
+        [
+            "minecraft:block/cube_column",
+            "minecraft:block/oak_log",
+            "minecraft:block/oak_log_horizontal"
+        ]
+    );
+    assert_eq!(
+        manifest.resources[&ResourceKind::Texture][0].location,
+        "minecraft:block/oak_log"
+    );
+
+    let json = serde_json::to_string(&manifest).unwrap();
+    assert!(json.contains(r#""block_states":[{"location":"minecraft:oak_log"}]"#));
+    assert_eq!(serde_json::from_str::<Manifest>(&json).unwrap(), manifest);
+}
+
+#[test]
+fn resource_hash() {
+    let pack = TempPack::new();
+    pack.write("assets/minecraft/blockstates/stone.json", "{}")
+        .write("assets/minecraft/blockstates/dirt.json", "{ }")
+        .write("assets/minecraft/blockstates/granite.json", "{}");
+    let assets = pack.asset_pack();
+
+    let stone = ResourceIdentifier::blockstates("stone");
+    let hash = assets.resource_hash(&stone).unwrap();
+    assert_eq!(assets.resource_hash(&stone).unwrap(), hash);
+    // 64-bit FNV-1a of `{}`, which must not change between runs.
+    assert_eq!(hash, 0x08f4_4b07_b590_1a25);
+
+    let dirt = ResourceIdentifier::blockstates("dirt");
+    assert_ne!(assets.resource_hash(&dirt).unwrap(), hash);
+
+    let granite = ResourceIdentifier::blockstates("granite");
+    assert_eq!(assets.resource_hash(&granite).unwrap(), hash);
+
+    assert_matches!(
+        assets.resource_hash(&ResourceIdentifier::blockstates("missing")),
+        Err(Error::NotFound { .. })
+    );
+
+    let manifest = assets.export_manifest_with_hashes("minecraft").unwrap();
+    for entry in &manifest.resources[&ResourceKind::BlockStates] {
+        let expected = if entry.location == "minecraft:dirt" {
+            assets.resource_hash(&dirt).unwrap()
+        } else {
+            hash
+        };
+        assert_eq!(entry.hash, Some(expected));
+    }
+}
+
+#[test]
+fn render_type() {
+    let pack = TempPack::new();
+    pack.write(
+        "assets/minecraft/models/block/glass.json",
+        r#"{
+            "parent": "block/cube_all",
+            "render_type": "cutout",
+            "textures": { "all": "block/glass" }
+        }"#,
+    )
+    .write(
+        "assets/minecraft/models/block/stained_glass.json",
+        r#"{ "parent": "block/glass" }"#,
+    )
+    .write(
+        "assets/minecraft/models/block/cube_all.json",
+        r#"{ "render_type": "solid" }"#,
+    );
+    let assets = pack.asset_pack();
+
+    let model = assets.load_block_model("glass").unwrap();
+    assert_eq!(model.render_type.as_deref(), Some("cutout"));
+    assert!(model.extra.is_empty());
+
+    let json = serde_json::to_string(&model).unwrap();
+    assert!(json.contains(r#""render_type":"cutout""#));
+    assert_eq!(serde_json::from_str::<Model>(&json).unwrap(), model);
+
+    let resolved = assets.load_block_model_resolved("stained_glass").unwrap();
+    assert_eq!(resolved.render_type.as_deref(), Some("cutout"));
+}
+
+#[test]
+fn model_resolver_resolve() {
+    let pack = oak_log_pack();
+    let assets = pack.asset_pack();
+
+    let resolved = ModelResolver::resolve(&ResourceIdentifier::block_model("oak_log"), |id| {
+        assets.load_block_model(id.as_str())
+    })
+    .unwrap();
+
+    assert_eq!(
+        resolved.chain,
+        assets.load_block_model_recursive("oak_log").unwrap()
+    );
+    assert_eq!(resolved.textures.get("particle"), Some("block/oak_log"));
+    assert_eq!(resolved.textures.get("end"), Some("block/oak_log_top"));
+
+    let result = ModelResolver::resolve(&ResourceIdentifier::block_model("missing"), |id| {
+        assets.load_block_model(id.as_str())
+    });
+    assert_matches!(result, Err(Error::NotFound { .. }));
+}
+
+#[test]
+fn validate_blockstate_references() {
+    let pack = oak_log_pack();
+    pack.write(
+        "assets/minecraft/blockstates/oak_fence.json",
+        r#"{
+            "multipart": [
+                { "apply": { "model": "block/oak_fence_post" } },
+                { "when": { "north": "true" }, "apply": { "model": "block/oak_fence_side" } },
+                { "when": { "south": "true" }, "apply": { "model": "block/oak_fence_side" } },
+                { "when": { "east": "true" }, "apply": { "model": "block/oak_log" } }
+            ]
+        }"#,
+    )
+    .write(
+        "assets/minecraft/blockstates/stone.json",
+        r#"{ "variants": { "": [{ "model": "block/stone" }, { "model": "stone_mirrored" }] } }"#,
+    )
+    .write("assets/minecraft/models/block/oak_fence_post.json", "{}");
+    let assets = pack.asset_pack();
+
+    let mut issues: Vec<_> = assets
+        .validate_blockstate_references("minecraft")
+        .unwrap()
+        .into_iter()
+        .map(|issue| (issue.blockstates.as_str().to_string(), issue.model))
+        .collect();
+    issues.sort();
+
+    assert_eq!(
+        issues,
+        [
+            (
+                String::from("minecraft:oak_fence"),
+                String::from("block/oak_fence_side")
+            ),
+            (String::from("minecraft:stone"), String::from("block/stone")),
+            (
+                String::from("minecraft:stone"),
+                String::from("stone_mirrored")
+            ),
+        ]
+    );
+
+    assert!(assets
+        .validate_blockstate_references("custom")
+        .unwrap()
+        .is_empty());
+}
+
+#[test]
+fn glob_resources() {
+    let pack = TempPack::new();
+    for model in ["oak_planks", "birch_planks", "stone", "oak_stairs"] {
+        pack.write(
+            format!("assets/minecraft/models/block/{}.json", model),
+            "{}",
+        );
+    }
+    for texture in [
+        "item/layer0",
+        "item/bow/layer0",
+        "item/bow/pulling/layer1",
+        "item/bow/base",
+        "block/oak_planks",
+    ] {
+        pack.write(format!("assets/minecraft/textures/{}.png", texture), "");
+    }
+    let assets = pack.asset_pack();
+
+    let glob = |kind, pattern| -> Vec<String> {
+        assets
+            .glob_resources("minecraft", kind, pattern)
+            .unwrap()
+            .iter()
+            .map(|id| id.as_str().to_string())
+            .collect()
+    };
+
+    assert_eq!(
+        glob(ResourceKind::BlockModel, "block/*_planks"),
+        ["minecraft:block/birch_planks", "minecraft:block/oak_planks"]
+    );
+    assert_eq!(
+        glob(ResourceKind::BlockModel, "block/oak_*"),
+        ["minecraft:block/oak_planks", "minecraft:block/oak_stairs"]
+    );
+    assert!(glob(ResourceKind::BlockModel, "*_planks").is_empty());
+    assert_eq!(glob(ResourceKind::BlockModel, "**").len(), 4);
+
+    assert_eq!(
+        glob(ResourceKind::Texture, "item/**/layer*"),
+        [
+            "minecraft:item/bow/layer0",
+            "minecraft:item/bow/pulling/layer1",
+            "minecraft:item/layer0"
+        ]
+    );
+    assert_eq!(
+        glob(ResourceKind::Texture, "item/*/layer0"),
+        ["minecraft:item/bow/layer0"]
+    );
+    assert_eq!(
+        glob(ResourceKind::Texture, "**planks"),
+        ["minecraft:block/oak_planks"]
+    );
+}
+
+#[test]
+fn glob_resources_with_many_wildcards() {
+    let pack = TempPack::new();
+    let name = "a".repeat(40);
+    pack.write(format!("assets/minecraft/models/block/{}.json", name), "{}");
+    let assets = pack.asset_pack();
+
+    // Would take exponential time if each `*` retried every split of the
+    // remaining name.
+    let pattern = format!("block/{}b", "*a".repeat(20));
+    assert!(assets
+        .glob_resources("minecraft", ResourceKind::BlockModel, &pattern)
+        .unwrap()
+        .is_empty());
+
+    let pattern = format!("**{}", "*a".repeat(20));
+    assert_eq!(
+        assets
+            .glob_resources("minecraft", ResourceKind::BlockModel, &pattern)
+            .unwrap()
+            .len(),
+        1
+    );
+}
+
+#[test]
+fn blockstates_with_unknown_keys() {
+    let pack = TempPack::new();
+    pack.write(
+        "assets/mymod/blockstates/lamp.json",
+        r#"{
+            "forge_marker": 1,
+            "properties": { "lit": ["true", "false"] },
+            "variants": {
+                "lit=false": { "model": "mymod:block/lamp" },
+                "lit=true": { "model": "mymod:block/lamp_on" }
+            }
+        }"#,
+    )
+    .write(
+        "assets/mymod/blockstates/pipe.json",
+        r#"{
+            "forge_marker": 1,
+            "multipart": [{ "apply": { "model": "mymod:block/pipe_core" } }]
+        }"#,
+    );
+    let assets = pack.asset_pack();
+
+    let states = assets.load_blockstates("mymod:lamp").unwrap();
+    assert_eq!(states.variants().unwrap().len(), 2);
+
+    let states = assets.load_blockstates("mymod:pipe").unwrap();
+    assert_eq!(states.cases().unwrap().len(), 1);
+}
+
+#[test]
+fn load_from_other_namespace() {
+    let pack = TempPack::new();
+    pack.write(
+        "assets/mymod/blockstates/custom_block.json",
+        r#"{ "variants": { "": { "model": "mymod:block/custom_block" } } }"#,
+    )
+    .write(
+        "assets/mymod/models/block/custom_block.json",
+        r#"{ "parent": "mymod:block/custom_parent" }"#,
+    )
+    .write(
+        "assets/mymod/models/block/custom_parent.json",
+        r#"{ "textures": { "all": "mymod:block/custom_block" } }"#,
+    )
+    .write(
+        "assets/mymod/models/item/custom_item.json",
+        r#"{ "parent": "item/generated" }"#,
+    )
+    .write("assets/minecraft/models/block/custom_block.json", "{}");
+    let assets = pack.asset_pack();
+
+    let states = assets.load_blockstates("mymod:custom_block").unwrap();
+    let model = &states.variants().unwrap()[""].models()[0].model;
+    assert_eq!(model, "mymod:block/custom_block");
+
+    for id in ["mymod:custom_block", "mymod:block/custom_block"] {
+        let model = assets.load_block_model(id).unwrap();
+        assert_eq!(model.parent.as_deref(), Some("mymod:block/custom_parent"));
+    }
+    assert_eq!(
+        assets.load_block_model("custom_block").unwrap().parent,
+        None
+    );
+    assert_eq!(
+        assets
+            .load_block_model_recursive("mymod:custom_block")
+            .unwrap()
+            .len(),
+        2
+    );
+
+    let model = assets.load_item_model("mymod:custom_item").unwrap();
+    assert_eq!(model.parent.as_deref(), Some("item/generated"));
+
+    assert_matches!(
+        assets.load_blockstates("custom_block"),
+        Err(Error::NotFound { .. })
+    );
+}
+
+#[test]
+fn texture_meta_file_names_agree() {
+    let pack = TempPack::new();
+    pack.write("assets/minecraft/textures/block/kelp.png", "")
+        .write("assets/minecraft/textures/block/kelp.png.mcmeta", "{}")
+        .write("assets/minecraft/textures/block/v1.2.png", "");
+    let assets = pack.asset_pack();
+
+    let meta = ResourceIdentifier::new(ResourceKind::TextureMeta, "block/kelp");
+    let texture = ResourceIdentifier::texture("block/kelp");
+
+    let meta_path = ResourcePath::for_resource(pack.dir.path(), &meta).into_inner();
+    let texture_path = ResourcePath::for_resource(pack.dir.path(), &texture).into_inner();
+    assert!(meta_path.ends_with("textures/block/kelp.png.mcmeta"));
+    assert!(texture_path.ends_with("textures/block/kelp.png"));
+
+    assert_eq!(meta.file_name(), "kelp.png.mcmeta");
+    assert_eq!(texture.file_name(), "kelp.png");
+    assert_eq!(
+        meta_path.file_name().unwrap().to_str(),
+        Some(meta.file_name().as_str())
+    );
+
+    assert_eq!(
+        ResourceIdentifier::from_path(pack.dir.path(), &meta_path),
+        Some(meta.to_owned())
+    );
+    assert_eq!(
+        ResourceIdentifier::from_path(pack.dir.path(), &texture_path),
+        Some(texture.to_owned())
+    );
+
+    let (id, path) = assets
+        .iter_resources("minecraft", ResourceKind::TextureMeta)
+        .unwrap()
+        .next()
+        .unwrap();
+    assert_eq!(id, meta);
+    assert_eq!(path, meta_path);
+    assert!(assets.resource_exists(&meta));
+
+    // Dots in the name of a resource are kept.
+    let dotted = ResourceIdentifier::texture("block/v1.2");
+    assert!(ResourcePath::for_resource(pack.dir.path(), &dotted)
+        .into_inner()
+        .ends_with("block/v1.2.png"));
+    assert!(assets.resource_exists(&dotted));
+}
+
+#[test]
+fn preload() {
+    let pack = oak_log_pack();
+    pack.write(
+        "assets/minecraft/models/block/stripped_oak_log.json",
+        r#"{ "parent": "block/cube_column" }"#,
+    );
+    let provider = pack.counting_provider();
+    let loads = provider.loads();
+    let assets = AssetPack::new(provider).with_cache();
+
+    assert_matches!(
+        assets.preload("minecraft", &[ResourceKind::BlockStates]),
+        Err(Error::IoError(e)) if e.kind() == io::ErrorKind::InvalidInput
+    );
+    assert_eq!(total_loads(&loads), 0);
+
+    // There are no item models, so only the block models are loaded.
+    assets
+        .preload(
+            "minecraft",
+            &[ResourceKind::BlockModel, ResourceKind::ItemModel],
+        )
+        .unwrap();
+    assert_eq!(total_loads(&loads), 4);
+
+    let models = assets
+        .load_block_model_recursive("stripped_oak_log")
+        .unwrap();
+    assert_eq!(models.len(), 2);
+    assets.load_block_model("minecraft:block/oak_log").unwrap();
+    assert_eq!(total_loads(&loads), 4);
+
+    // Cached models are not read again.
+    assets
+        .preload("minecraft", &[ResourceKind::BlockModel])
+        .unwrap();
+    assert_eq!(total_loads(&loads), 4);
+}
+
+#[test]
+fn load_blockstates_resolved() {
+    let pack = oak_log_pack();
+    pack.write(
+        "assets/minecraft/blockstates/oak_fence.json",
+        r#"{
+            "multipart": [
+                { "apply": { "model": "block/oak_log" } },
+                {
+                    "when": { "north": "true" },
+                    "apply": { "model": "block/oak_log_horizontal", "y": 90, "uvlock": true }
+                }
+            ]
+        }"#,
+    );
+    let provider = pack.counting_provider();
+    let loads = provider.loads();
+    let assets = AssetPack::new(provider);
+
+    let resolved = assets.load_blockstates_resolved("oak_log").unwrap();
+    assert!(resolved.cases().is_none());
+
+    let variants = resolved.variants().unwrap();
+    assert_eq!(variants.len(), 3);
+
+    let placed = &variants["axis=x"];
+    assert_eq!(placed.len(), 1);
+    assert_eq!((placed[0].x, placed[0].y), (90, 90));
+    assert_eq!(placed[0].model.parent, None);
+    let textures = placed[0].model.textures.as_ref().unwrap();
+    assert_eq!(textures.get("particle"), Some("block/oak_log"));
+
+    assert_eq!((variants["axis=z"][0].x, variants["axis=z"][0].y), (90, 0));
+    assert_eq!(variants["axis=x"][0].model, variants["axis=z"][0].model);
+
+    // `axis=x` and `axis=z` share a model, which is only resolved once.
+    let horizontal = format!(
+        "{:?}",
+        ResourceIdentifier::block_model("minecraft:block/oak_log_horizontal")
+    );
+    assert_eq!(loads.lock().unwrap()[&horizontal], 1);
+
+    let resolved = assets.load_blockstates_resolved("oak_fence").unwrap();
+    let cases = resolved.cases().unwrap();
+    assert_eq!(cases.len(), 2);
+    assert!(cases[0].when.is_none());
+    assert!(cases[1].when.is_some());
+    assert_eq!(cases[1].models[0].y, 90);
+    assert!(cases[1].models[0].uv_lock);
+    assert_eq!(cases[0].models[0].model, variants["axis=y"][0].model);
+
+    assert_matches!(
+        assets.load_blockstates_resolved("missing"),
+        Err(Error::NotFound { .. })
+    );
+}
+
+#[test]
+fn texture_diff() {
+    let old = TempPack::new();
+    old.write("assets/minecraft/textures/block/stone.png", "")
+        .write("assets/minecraft/textures/block/grass_path_top.png", "")
+        .write("assets/minecraft/textures/item/apple.png", "");
+    let new = TempPack::new();
+    new.write("assets/minecraft/textures/block/stone.png", "changed")
+        .write("assets/minecraft/textures/block/dirt_path_top.png", "")
+        .write("assets/minecraft/textures/item/apple.png", "");
+    let empty = TempPack::new();
+
+    let (added, removed) = old
+        .asset_pack()
+        .texture_diff(&new.asset_pack(), "minecraft")
+        .unwrap();
+    assert_eq!(
+        added,
+        [ResourceIdentifier::texture("minecraft:block/dirt_path_top")]
+    );
+    assert_eq!(
+        removed,
+        [ResourceIdentifier::texture(
+            "minecraft:block/grass_path_top"
+        )]
+    );
+    assert_eq!(added[0].as_str(), "minecraft:block/dirt_path_top");
+
+    let (added, removed) = new
+        .asset_pack()
+        .texture_diff(&empty.asset_pack(), "minecraft")
+        .unwrap();
+    assert!(added.is_empty());
+    assert_eq!(removed.len(), 3);
+}
+
+#[cfg(feature = "json5")]
+#[test]
+fn lenient_parsing() {
+    let pack = TempPack::new();
+    pack.write(
+        "assets/minecraft/blockstates/lamp.json",
+        r#"{
+            // Lit lamps use a brighter texture.
+            "variants": {
+                "lit=false": { "model": "block/lamp", },
+                "lit=true": { model: 'block/lamp_on' }, /* unquoted key */
+            },
+        }"#,
+    )
+    .write(
+        "assets/minecraft/blockstates/broken.json",
+        r#"{ "variants": { "": { "model": 7 } } }"#,
+    );
+
+    assert_matches!(
+        pack.asset_pack().load_blockstates("lamp"),
+        Err(Error::Parse { .. })
+    );
+
+    let assets = pack.asset_pack().lenient(true);
+    let states = assets.load_blockstates("lamp").unwrap();
+    let variants = states.variants().unwrap();
+    assert_eq!(variants.len(), 2);
+    assert_eq!(variants["lit=true"].models()[0].model, "block/lamp_on");
+
+    let err = assets.load_blockstates("broken").unwrap_err();
+    assert_matches!(err, Error::Parse { path, .. } if path.ends_with("blockstates/broken.json"));
+}
+
+#[test]
+fn enumerate_all() {
+    let pack = oak_log_pack();
+    pack.write("assets/minecraft/textures/block/oak_log.png", "")
+        .write("assets/minecraft/textures/block/oak_log.png.mcmeta", "{}")
+        .write(
+            "data/minecraft/tags/blocks/logs.json",
+            r#"{ "values": [] }"#,
+        )
+        .write("assets/custom/blockstates/ruby_ore.json", "{}");
+    let assets = pack.asset_pack();
+
+    let all = assets.enumerate_all("minecraft").unwrap();
+    assert_eq!(
+        all,
+        [
+            ResourceIdentifier::blockstates("minecraft:oak_log"),
+            ResourceIdentifier::block_model("minecraft:block/cube_column"),
+            ResourceIdentifier::block_model("minecraft:block/oak_log"),
+            ResourceIdentifier::block_model("minecraft:block/oak_log_horizontal"),
+            ResourceIdentifier::texture("minecraft:block/oak_log"),
+            ResourceIdentifier::new(ResourceKind::TextureMeta, "minecraft:block/oak_log"),
+            ResourceIdentifier::tag("minecraft:blocks/logs"),
+        ]
+    );
+
+    let manifest = assets.export_manifest("minecraft").unwrap();
+    assert_eq!(all.len(), manifest.len());
+
+    assert!(assets.enumerate_all("nonexistent").unwrap().is_empty());
+}
+
+#[test]
+fn enumerate_unknown() {
+    let pack = oak_log_pack();
+    pack.write("assets/minecraft/sounds.json", "{}")
+        .write("assets/minecraft/sounds/ambient/cave1.ogg", "")
+        .write("assets/minecraft/shaders/core/rendertype_solid.json", "{}")
+        .write("assets/minecraft/models/entity/chest.json", "{}")
+        .write("assets/minecraft/textures/block/oak_log.png", "")
+        .write(
+            "assets/minecraft/font/default.json",
+            r#"{ "providers": [] }"#,
+        );
+    let assets = pack.asset_pack();
+
+    let unknown = assets.enumerate_unknown("minecraft").unwrap();
+    let names: Vec<_> = unknown.iter().map(|(name, _)| name.as_str()).collect();
+    assert_eq!(names, ["models/entity", "shaders", "sounds"]);
+    assert_eq!(
+        unknown[0].1,
+        pack.dir.path().join("assets/minecraft/models/entity")
+    );
+    assert!(unknown.iter().all(|(_, path)| path.is_dir()));
+
+    let err = assets.enumerate_unknown("nonexistent").unwrap_err();
+    assert_matches!(err, Error::IoError(e) if e.kind() == io::ErrorKind::NotFound);
+}
+
+#[test]
+fn resolve_item_overrides() {
+    let pack = TempPack::new();
+    pack.write(
+        "assets/minecraft/models/item/bow.json",
+        r#"{
+            "parent": "item/generated",
+            "textures": { "layer0": "item/bow" },
+            "overrides": [
+                { "predicate": { "pulling": 1 }, "model": "item/bow_pulling_0" },
+                { "predicate": { "pulling": 1, "pull": 0.65 }, "model": "item/bow_pulling_1" },
+                { "predicate": { "pulling": 1, "pull": 0.9 }, "model": "minecraft:item/bow_pulling_2" }
+            ]
+        }"#,
+    )
+    .write(
+        "assets/minecraft/models/item/generated.json",
+        r#"{ "parent": "builtin/generated", "display": {} }"#,
+    );
+    for i in 0..3 {
+        pack.write(
+            format!("assets/minecraft/models/item/bow_pulling_{}.json", i),
+            &format!(
+                r#"{{ "parent": "item/bow", "textures": {{ "layer0": "item/bow_pulling_{}" }} }}"#,
+                i
+            ),
+        );
+    }
+    let assets = pack.asset_pack();
+
+    let overrides = assets.resolve_item_overrides("bow").unwrap();
+    assert_eq!(overrides.len(), 3);
+
+    let (predicate, model) = &overrides[0];
+    assert_eq!(predicate, &HashMap::from([(String::from("pulling"), 1.0)]));
+    assert!(model.parent.is_none());
+    assert_eq!(
+        model.textures.as_ref().unwrap()["layer0"].location(),
+        Some("item/bow_pulling_0")
+    );
+
+    let (predicate, model) = &overrides[2];
+    assert_eq!(predicate["pulling"], 1.0);
+    assert!((predicate["pull"] - 0.9).abs() < 1e-6);
+    assert_eq!(
+        model.textures.as_ref().unwrap()["layer0"].location(),
+        Some("item/bow_pulling_2")
+    );
+    assert!(model.display.is_some());
+
+    // Models without overrides have none to resolve.
+    assert!(assets
+        .resolve_item_overrides("generated")
+        .unwrap()
+        .is_empty());
+}
+
+#[test]
+fn enumerate_all_models() {
+    let pack = oak_log_pack();
+    pack.write("assets/minecraft/models/item/oak_log.json", "{}")
+        .write("assets/minecraft/models/item/wooden_axe.json", "{}")
+        .write("assets/minecraft/models/entity/chest.json", "{}");
+    let assets = pack.asset_pack();
+
+    let models = assets.enumerate_all_models("minecraft").unwrap();
+    assert_eq!(
+        models,
+        [
+            (
+                ResourceKind::BlockModel,
+                ResourceIdentifier::block_model("minecraft:block/cube_column")
+            ),
+            (
+                ResourceKind::BlockModel,
+                ResourceIdentifier::block_model("minecraft:block/oak_log")
+            ),
+            (
+                ResourceKind::BlockModel,
+                ResourceIdentifier::block_model("minecraft:block/oak_log_horizontal")
+            ),
+            (
+                ResourceKind::ItemModel,
+                ResourceIdentifier::item_model("minecraft:item/oak_log")
+            ),
+            (
+                ResourceKind::ItemModel,
+                ResourceIdentifier::item_model("minecraft:item/wooden_axe")
+            ),
+        ]
+    );
+    assert!(assets.load_model_recursive(&models[4].1).is_ok());
+}
+
+#[test]
+fn save_resources() {
+    let pack = oak_log_pack();
+    let assets = pack.asset_pack().with_cache().with_enumeration_cache();
+
+    let states = assets.load_blockstates("oak_log").unwrap();
+    assets.save_blockstates("mymod:maple_log", &states).unwrap();
+    assert_eq!(assets.load_blockstates("mymod:maple_log").unwrap(), states);
+
+    let path = pack
+        .dir
+        .path()
+        .join("assets/mymod/blockstates/maple_log.json");
+    let json = fs::read_to_string(path).unwrap();
+    assert!(json.starts_with("{\n  \"variants\": {\n    \""), "{}", json);
+
+    // Saving a model replaces the cached copy.
+    let mut model = assets.load_block_model("oak_log").unwrap();
+    assert_eq!(model.ambient_occlusion, None);
+    model.ambient_occlusion = Some(false);
+    assets.save_block_model("oak_log", &model).unwrap();
+    assert_eq!(
+        assets
+            .load_block_model("oak_log")
+            .unwrap()
+            .ambient_occlusion,
+        Some(false)
+    );
+
+    // New resources show up in cached enumerations.
+    assert_eq!(
+        assets
+            .enumerate_resources("mymod", ResourceKind::ItemModel)
+            .unwrap_err()
+            .kind(),
+        io::ErrorKind::NotFound
+    );
+    assets
+        .save_item_model("mymod:item/tools/saw", &model)
+        .unwrap();
+    assert!(pack
+        .dir
+        .path()
+        .join("assets/mymod/models/item/tools/saw.json")
+        .is_file());
+    assert_eq!(
+        assets
+            .enumerate_resources("mymod", ResourceKind::ItemModel)
+            .unwrap()
+            .len(),
+        1
+    );
+
+    // Values that JSON cannot represent are not written.
+    let id = ResourceIdentifier::tag("mymod:blocks/unsaveable");
+    let value: HashMap<(u8, u8), u8> = [((0, 0), 0)].into_iter().collect();
+    let err = assets.save_resource(&id, &value).unwrap_err();
+    assert_matches!(
+        err,
+        Error::Serialize { path, .. } if path.ends_with("data/mymod/tags/blocks/unsaveable.json")
+    );
+    assert!(!pack
+        .dir
+        .path()
+        .join("data/mymod/tags/blocks/unsaveable.json")
+        .exists());
+
+    let assets = AssetPack::new(pack.counting_provider());
+    let err = assets.save_blockstates("oak_log", &states).unwrap_err();
+    assert_matches!(err, Error::IoError(e) if e.kind() == io::ErrorKind::Unsupported);
+}
+
+#[test]
+fn save_blockstates_invalidates_resolve_cache() {
+    let pack = oak_log_pack();
+    let assets = pack.asset_pack().with_resolve_cache(16);
+
+    let state = BlockStates::parse_variant_key("axis=y");
+    let placed = assets.resolve_block("oak_log", &state).unwrap();
+    assert_eq!(placed.len(), 1);
+    assert_eq!(placed[0].x, 0);
+
+    let mut states = assets.load_blockstates("oak_log").unwrap();
+    let variants = match states {
+        BlockStates::Variants { ref mut variants } => variants,
+        _ => unreachable!(),
+    };
+    variants.insert(String::from("axis=y"), variants["axis=x"].clone());
+    assets.save_blockstates("oak_log", &states).unwrap();
+
+    let placed = assets.resolve_block("oak_log", &state).unwrap();
+    assert_eq!(placed.len(), 1);
+    assert_eq!((placed[0].x, placed[0].y), (90, 90));
+    assert_eq!(
+        placed[0].model,
+        assets
+            .load_block_model_resolved("oak_log_horizontal")
+            .unwrap()
+    );
+}
+
+#[test]
+fn load_optifine_properties() {
+    let pack = TempPack::new();
+    pack.write(
+        "assets/minecraft/optifine/ctm/glass/glass.properties",
+        "# Connected glass\n\
+         method=ctm\n\
+         matchBlocks=glass \\\n    white_stained_glass\n\
+         tiles=0-46\n\
+         connect = block\n",
+    )
+    .write("assets/minecraft/optifine/ctm/glass/0.png", "");
+    let assets = pack.asset_pack();
+
+    let ids = assets
+        .enumerate_resources("minecraft", ResourceKind::OptifineProperties)
+        .unwrap();
+    assert_eq!(
+        ids,
+        [ResourceIdentifier::new(
+            ResourceKind::OptifineProperties,
+            "ctm/glass/glass"
+        )]
+    );
+
+    let properties = assets
+        .load_optifine_properties("minecraft", "ctm/glass/glass")
+        .unwrap();
+    let expected = Properties(HashMap::from([
+        (String::from("method"), String::from("ctm")),
+        (
+            String::from("matchBlocks"),
+            String::from("glass white_stained_glass"),
+        ),
+        (String::from("tiles"), String::from("0-46")),
+        (String::from("connect"), String::from("block")),
+    ]));
+    assert_eq!(properties, expected);
+
+    assert!(assets.enumerate_unknown("minecraft").unwrap().is_empty());
+}
+
+#[test]
+fn resolve_textures_across_three_levels() {
+    let pack = TempPack::new();
+    pack.write(
+        "assets/minecraft/models/block/grandparent.json",
+        r##"{
+            "textures": {
+                "particle": "#side",
+                "top": "#end",
+                "bottom": "block/grandparent_bottom",
+                "cycle_a": "#cycle_b",
+                "cycle_b": "#cycle_a"
+            }
+        }"##,
+    )
+    .write(
+        "assets/minecraft/models/block/parent.json",
+        r##"{
+            "parent": "block/grandparent",
+            "textures": { "side": "#all", "end": "#bottom" }
+        }"##,
+    )
+    .write(
+        "assets/minecraft/models/block/child.json",
+        r##"{
+            "parent": "block/parent",
+            "textures": { "all": "block/child_all", "front": "#top" }
+        }"##,
+    );
+    let assets = pack.asset_pack();
+
+    let models = assets.load_block_model_recursive("child").unwrap();
+    let textures = ModelResolver::resolve_textures(models.iter());
+
+    // Defined in the child, referenced through the parent by the grandparent.
+    assert_eq!(textures.get("particle"), Some("block/child_all"));
+    assert_eq!(textures.get("side"), Some("block/child_all"));
+
+    // Defined in the grandparent, referenced through the parent by the child.
+    assert_eq!(textures.get("front"), Some("block/grandparent_bottom"));
+    assert_eq!(textures.get("top"), Some("block/grandparent_bottom"));
+    assert_eq!(textures.get("end"), Some("block/grandparent_bottom"));
+
+    // Only the cycle is left dangling.
+    assert_eq!(textures.unresolved(), ["#cycle_a", "#cycle_b"]);
+
+    let model = assets.load_block_model_resolved("child").unwrap();
+    assert_eq!(model.textures.unwrap(), textures);
+}
+
+/// Returns the start of a PNG file with the given dimensions, which is all that
+/// is needed to read them.
+fn png_header(width: u32, height: u32) -> Vec<u8> {
+    let mut bytes = b"\x89PNG\r\n\x1a\n\0\0\0\x0dIHDR".to_vec();
+    bytes.extend(width.to_be_bytes());
+    bytes.extend(height.to_be_bytes());
+    bytes.extend([8, 6, 0, 0, 0]);
+    bytes
+}
+
+#[test]
+fn validate_texture_dimensions() {
+    let pack = TempPack::new();
+    pack.write_bytes(
+        "assets/minecraft/textures/block/stone.png",
+        &png_header(16, 16),
+    )
+    // Frames without an animation.
+    .write_bytes(
+        "assets/minecraft/textures/block/lava_still.png",
+        &png_header(16, 320),
+    )
+    // Properly animated.
+    .write_bytes(
+        "assets/minecraft/textures/block/fire_0.png",
+        &png_header(16, 512),
+    )
+    .write(
+        "assets/minecraft/textures/block/fire_0.png.mcmeta",
+        r#"{ "animation": {} }"#,
+    )
+    // Animated with a frame size that does not divide the texture.
+    .write_bytes(
+        "assets/minecraft/textures/block/prismarine.png",
+        &png_header(16, 360),
+    )
+    .write(
+        "assets/minecraft/textures/block/prismarine.png.mcmeta",
+        r#"{ "animation": { "height": 32, "frametime": 300 } }"#,
+    )
+    // Non-square textures outside of `block/` are fine.
+    .write_bytes(
+        "assets/minecraft/textures/gui/widgets.png",
+        &png_header(256, 128),
+    )
+    .write("assets/minecraft/textures/block/broken.png", "not a png");
+    // A texture that is listed but cannot be read.
+    #[cfg(unix)]
+    std::os::unix::fs::symlink(
+        pack.dir.path().join("missing.png"),
+        pack.dir
+            .path()
+            .join("assets/minecraft/textures/block/dangling.png"),
+    )
+    .unwrap();
+    let assets = pack.asset_pack();
+
+    let issues = assets.validate_texture_dimensions("minecraft").unwrap();
+    assert_eq!(
+        issues,
+        [
+            TextureIssue {
+                texture: ResourceIdentifier::texture("minecraft:block/lava_still"),
+                width: 16,
+                height: 320,
+                kind: TextureIssueKind::NotSquare,
+            },
+            TextureIssue {
+                texture: ResourceIdentifier::texture("minecraft:block/prismarine"),
+                width: 16,
+                height: 360,
+                kind: TextureIssueKind::PartialFrames {
+                    frame_width: 16,
+                    frame_height: 32
+                },
+            },
+        ]
+    );
+
+    assert!(assets
+        .validate_texture_dimensions("nonexistent")
+        .unwrap()
+        .is_empty());
+}
+
+#[test]
+fn namespaces() {
+    let pack = oak_log_pack();
+    pack.write("assets/realms/lang/en_us.json", "{}")
+        .write("assets/.hidden/lang/en_us.json", "{}")
+        .write("assets/_skip/lang/en_us.json", "{}")
+        .write(
+            "data/minecraft/tags/blocks/logs.json",
+            r#"{ "values": [] }"#,
+        )
+        .write("data/mymod/tags/blocks/logs.json", r#"{ "values": [] }"#);
+    let assets = pack.asset_pack();
+
+    assert_eq!(
+        assets.namespaces().unwrap(),
+        ["minecraft", "mymod", "realms"]
+    );
+
+    let empty = TempPack::new();
+    assert!(empty.asset_pack().namespaces().unwrap().is_empty());
+}
+
+#[test]
+fn share_asset_pack_between_threads() {
+    let pack = oak_log_pack();
+    let assets = Arc::new(pack.asset_pack().with_cache());
+
+    let handles: Vec<_> = (0..2)
+        .map(|_| {
+            let assets = Arc::clone(&assets);
+            std::thread::spawn(move || {
+                let states = assets.load_blockstates("oak_log").unwrap();
+                let models = assets.load_block_model_recursive("oak_log").unwrap();
+                (states, models)
+            })
+        })
+        .collect();
+
+    let results: Vec<_> = handles
+        .into_iter()
+        .map(|handle| handle.join().unwrap())
+        .collect();
+
+    assert_eq!(results[0], results[1]);
+    assert_eq!(results[0].0.variants().unwrap().len(), 3);
+}
+
+#[test]
+fn enumerate_worldgen() {
+    let pack = TempPack::new();
+    pack.write("data/mymod/worldgen/biome/dark_forest.json", "{}")
+        .write("data/mymod/worldgen/biome/caves/crystal.json", "{}")
+        .write("data/mymod/worldgen/biome/_template.json", "{}")
+        .write("data/mymod/worldgen/placed_feature/ruby_ore.json", "{}");
+    let assets = pack.asset_pack();
+
+    let mut biomes = assets
+        .enumerate_resources("mymod", ResourceKind::WorldgenBiome)
+        .unwrap();
+    biomes.sort();
+    assert_eq!(
+        biomes,
+        [
+            ResourceIdentifier::new(ResourceKind::WorldgenBiome, "caves/crystal"),
+            ResourceIdentifier::new(ResourceKind::WorldgenBiome, "dark_forest"),
+        ]
+    );
+
+    let all = assets.enumerate_all("mymod").unwrap();
+    assert_eq!(
+        all,
+        [
+            ResourceIdentifier::new(ResourceKind::WorldgenBiome, "mymod:caves/crystal"),
+            ResourceIdentifier::new(ResourceKind::WorldgenBiome, "mymod:dark_forest"),
+            ResourceIdentifier::new(ResourceKind::WorldgenPlacedFeature, "mymod:ruby_ore"),
+        ]
+    );
+    assert_eq!(assets.load_resource(&all[1]).unwrap(), b"{}");
+
+    let id = ResourceIdentifier::from_path(
+        pack.dir.path(),
+        pack.dir
+            .path()
+            .join("data/mymod/worldgen/placed_feature/ruby_ore.json"),
+    )
+    .unwrap();
+    assert_eq!(id.kind(), ResourceKind::WorldgenPlacedFeature);
+    assert_eq!(id.as_str(), "mymod:ruby_ore");
+
+    let err = assets
+        .enumerate_resources("mymod", ResourceKind::WorldgenStructure)
+        .unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::NotFound);
+}
+
+#[test]
+fn load_enumerated() {
+    let pack = oak_log_pack();
+    pack.write("assets/minecraft/models/block/broken.json", "{ not json");
+    let assets = pack.asset_pack();
+
+    let models: Vec<_> = assets
+        .load_enumerated::<Model>("minecraft", ResourceKind::BlockModel)
+        .unwrap()
+        .collect();
+
+    let ids: Vec<&str> = models
+        .iter()
+        .map(|item| match item {
+            Ok((id, _)) => id.as_str(),
+            Err(_) => "error",
+        })
+        .collect();
+    assert_eq!(
+        ids,
+        [
+            "error",
+            "minecraft:block/cube_column",
+            "minecraft:block/oak_log",
+            "minecraft:block/oak_log_horizontal",
+        ]
+    );
+    assert_matches!(&models[0], Err(Error::Parse { path, .. }) if path.ends_with("broken.json"));
+
+    let (_, oak_log) = models[2].as_ref().unwrap();
+    assert_eq!(oak_log, &assets.load_block_model("oak_log").unwrap());
+
+    let mut missing = assets
+        .load_enumerated::<Model>("nonexistent", ResourceKind::BlockModel)
+        .unwrap();
+    assert!(missing.next().is_none());
+}
+
+#[test]
+fn custom_resource_kind() {
+    let kind = ResourceKind::Custom {
+        category: ResourceCategory::Assets,
+        directory: "postprocessing",
+        extension: "json",
+    };
+
+    let pack = TempPack::new();
+    pack.write(
+        "assets/mymod/postprocessing/bloom.json",
+        r#"{ "intensity": 2 }"#,
+    )
+    .write("assets/mymod/postprocessing/blur.json", "{}")
+    .write("assets/mymod/postprocessing/notes.txt", "");
+    let assets = pack.asset_pack();
+
+    let mut ids = assets.enumerate_resources("mymod", kind).unwrap();
+    ids.sort();
+    assert_eq!(
+        ids,
+        [
+            ResourceIdentifier::new(kind, "bloom"),
+            ResourceIdentifier::new(kind, "blur"),
+        ]
+    );
+
+    let loaded: Vec<(ResourceIdentifier, serde_json::Value)> = assets
+        .load_enumerated("mymod", kind)
+        .unwrap()
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(loaded[0].0.as_str(), "mymod:bloom");
+    assert_eq!(loaded[0].1["intensity"], 2);
+
+    let id = ResourceIdentifier::new(kind, "mymod:blur");
+    assert_eq!(assets.load_resource(&id).unwrap(), b"{}");
+    assert_eq!(
+        ResourcePath::for_resource(pack.dir.path(), &id).into_inner(),
+        pack.dir
+            .path()
+            .join("assets/mymod/postprocessing/blur.json")
+    );
+}
+
+#[test]
+fn particle_texture_without_elements() {
+    let pack = TempPack::new();
+    pack.write(
+        "assets/minecraft/models/block/chest.json",
+        r#"{ "textures": { "particle": "block/oak_planks" } }"#,
+    )
+    .write(
+        "assets/minecraft/models/block/ender_chest.json",
+        r##"{ "parent": "block/template_chest", "textures": { "planks": "block/obsidian" } }"##,
+    )
+    .write(
+        "assets/minecraft/models/block/template_chest.json",
+        r##"{ "textures": { "particle": "#planks" } }"##,
+    );
+    let assets = pack.asset_pack();
+
+    let chest = assets.load_block_model_resolved("chest").unwrap();
+    assert_eq!(chest.elements, None);
+    assert_eq!(chest.particle_texture(), Some("block/oak_planks"));
+
+    let ender_chest = assets.load_block_model_resolved("ender_chest").unwrap();
+    assert_eq!(ender_chest.particle_texture(), Some("block/obsidian"));
+
+    let textures = assets
+        .collect_model_textures(ResourceIdentifier::block_model("ender_chest"))
+        .unwrap();
+    assert_eq!(
+        textures,
+        HashSet::from([ResourceIdentifier::texture("minecraft:block/obsidian")])
+    );
+}